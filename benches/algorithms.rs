@@ -0,0 +1,220 @@
+// Micro benchmarks of the hot functions of the solutions on generated inputs of increasing size
+// Run with `cargo bench`, `cargo bench -- day_19` only runs the benchmarks whose name contains day_19
+use aoc2021::bench::Statistics;
+use aoc2021::days::day_14::{self, Day14};
+use aoc2021::days::day_15::{self, Day15};
+use aoc2021::days::day_16::{self, Day16};
+use aoc2021::days::day_18::{self, Day18};
+use aoc2021::days::day_19::{self, Day19};
+use aoc2021::days::day_20::{self, Day20};
+use aoc2021::days::day_22::{self, Day22};
+use aoc2021::generate::Rng;
+use aoc2021::parse;
+use aoc2021::Solution;
+use std::env;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+// All inputs are generated with the same seed, so the timings of different commits are comparable
+const SEED: u64 = 2021;
+const SAMPLES: usize = 20;
+// Fast functions are repeated within a sample until it takes at least this long
+const MIN_SAMPLE_TIME: Duration = Duration::from_millis(10);
+
+// Function under test for a single input size
+// The setup parses the generated input and returns the measured work, setup time is not measured
+struct Benchmark {
+    name: &'static str,
+    sizes: &'static [usize],
+    setup: fn(usize) -> Box<dyn FnMut()>,
+}
+
+fn generated_input<S: Solution>(size: usize) -> S::Input {
+    let input = S::generate(&mut Rng::new(SEED), size);
+    match S::parse(&input) {
+        Ok(input) => input,
+        Err(error) => panic!("Generated input of day {} is invalid:\n{}", S::DAY, error),
+    }
+}
+
+fn replacement_step(size: usize) -> Box<dyn FnMut()> {
+    // The size is the length of the polymer template
+    let (template, rules) = generated_input::<Day14>(size);
+    let pair_counter = day_14::initial_pairs_count(&template);
+    let char_counter = day_14::initial_chars_count(&template);
+    Box::new(move || {
+        black_box(day_14::execute_replacement_step(
+            pair_counter.clone(),
+            char_counter.clone(),
+            &rules,
+        ));
+    })
+}
+
+fn min_path_to_target(size: usize) -> Box<dyn FnMut()> {
+    // The size is the width and height of the risk level map
+    let map = generated_input::<Day15>(size);
+    Box::new(move || {
+        black_box(day_15::find_min_path_to_target(&map));
+    })
+}
+
+fn packet_parsing(size: usize) -> Box<dyn FnMut()> {
+    // The size is the number of literal value packets in the transmission
+    let input = Day16::generate(&mut Rng::new(SEED), size);
+    let line = parse::lines(Day16::DAY, &input).next().unwrap();
+    let binary_string = day_16::hex_to_binary_string(&line).unwrap();
+    Box::new(move || {
+        let _ = black_box(day_16::parse_packet(&binary_string));
+    })
+}
+
+fn snailfish_addition(size: usize) -> Box<dyn FnMut()> {
+    // The size is the number of snailfish numbers summed up
+    let numbers = generated_input::<Day18>(size);
+    Box::new(move || {
+        black_box(
+            numbers
+                .iter()
+                .cloned()
+                .reduce(day_18::add_snailfish_numbers),
+        );
+    })
+}
+
+fn scanner_alignment(size: usize) -> Box<dyn FnMut()> {
+    // The size is the number of scanners, the last scanner is aligned to all others
+    // It only shares beacons with its predecessor, the last of the fixed scanners
+    let mut scanners = generated_input::<Day19>(size).scanners().to_vec();
+    let last_scanner = scanners.pop().unwrap();
+    let fixed_scanners = day_19::align_scanners(&scanners, 12).unwrap();
+    Box::new(move || {
+        black_box(day_19::align_scanner(&fixed_scanners, &last_scanner, 12));
+    })
+}
+
+fn image_enhancement(size: usize) -> Box<dyn FnMut()> {
+    // The size is the width and height of the image
+    let (enhancement, image) = generated_input::<Day20>(size);
+    Box::new(move || {
+        black_box(day_20::enhance_image(&image, &enhancement, 0));
+    })
+}
+
+fn reactor_reboot(size: usize) -> Box<dyn FnMut()> {
+    // The size is the number of reboot steps, the whole reactor is counted like in part 2
+    let steps = generated_input::<Day22>(size);
+    let reactor = steps
+        .iter()
+        .map(|(_, cuboid)| *cuboid)
+        .reduce(|reactor, cuboid| reactor.bounding_box(&cuboid))
+        .unwrap();
+    Box::new(move || {
+        black_box(day_22::lit_cubes_after_procedure_steps(&reactor, &steps));
+    })
+}
+
+const BENCHMARKS: [Benchmark; 7] = [
+    Benchmark {
+        name: "day_14::execute_replacement_step",
+        sizes: &[10, 100, 1000],
+        setup: replacement_step,
+    },
+    Benchmark {
+        name: "day_15::find_min_path_to_target",
+        sizes: &[50, 100, 200],
+        setup: min_path_to_target,
+    },
+    Benchmark {
+        name: "day_16::parse_packet",
+        sizes: &[10, 100, 1000],
+        setup: packet_parsing,
+    },
+    Benchmark {
+        name: "day_18::add_snailfish_numbers",
+        sizes: &[10, 50, 100],
+        setup: snailfish_addition,
+    },
+    Benchmark {
+        name: "day_19::align_scanner",
+        sizes: &[2, 4, 8],
+        setup: scanner_alignment,
+    },
+    Benchmark {
+        name: "day_20::enhance_image",
+        sizes: &[50, 100, 200],
+        setup: image_enhancement,
+    },
+    Benchmark {
+        name: "day_22::lit_cubes_after_procedure_steps",
+        sizes: &[20, 60, 120],
+        setup: reactor_reboot,
+    },
+];
+
+fn measure(run: &mut dyn FnMut()) -> (usize, Statistics) {
+    // Double the iterations per sample until a sample is long enough for a precise clock reading
+    let mut iterations = 1;
+    loop {
+        let start = Instant::now();
+        for _ in 0..iterations {
+            run();
+        }
+        if start.elapsed() >= MIN_SAMPLE_TIME {
+            break;
+        }
+        iterations *= 2;
+    }
+    // Samples are the mean time of a single call within the sample
+    let samples = (0..SAMPLES)
+        .map(|_| {
+            let start = Instant::now();
+            for _ in 0..iterations {
+                run();
+            }
+            start.elapsed() / iterations as u32
+        })
+        .collect::<Vec<Duration>>();
+    (iterations, Statistics::from_samples(&samples))
+}
+
+fn format_duration(duration: Duration) -> String {
+    // Single calls range from nanoseconds to seconds, the unit follows the magnitude
+    let nanos = duration.as_nanos();
+    match nanos {
+        0..=999 => format!("{} ns", nanos),
+        1_000..=999_999 => format!("{:.2} µs", nanos as f64 / 1e3),
+        1_000_000..=999_999_999 => format!("{:.2} ms", nanos as f64 / 1e6),
+        _ => format!("{:.2} s", nanos as f64 / 1e9),
+    }
+}
+
+fn main() {
+    // cargo passes --bench to the benchmark, the first other argument filters the benchmarks by name
+    let filter = env::args().skip(1).find(|arg| !arg.starts_with("--"));
+    println!(
+        "{:<40}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+        "Benchmark", "Size", "Iterations", "Min", "Median", "Max"
+    );
+    for benchmark in BENCHMARKS.iter() {
+        if filter
+            .as_ref()
+            .is_some_and(|filter| !benchmark.name.contains(filter.as_str()))
+        {
+            continue;
+        }
+        for size in benchmark.sizes.iter().cloned() {
+            let mut run = (benchmark.setup)(size);
+            let (iterations, statistics) = measure(&mut run);
+            println!(
+                "{:<40}  {:>5}  {:>10}  {:>10}  {:>10}  {:>10}",
+                benchmark.name,
+                size,
+                iterations,
+                format_duration(statistics.min),
+                format_duration(statistics.median),
+                format_duration(statistics.max)
+            );
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use crate::report::format_elapsed;
+use crate::solution::Part;
+use std::fmt;
+use std::time::Duration;
+
+// Measured phase of a puzzle, parsing the input or solving one of the parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Phase {
+    Parse,
+    Solve(Part),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => f.pad("parse"),
+            Phase::Solve(Part::One) => f.pad("part 1"),
+            Phase::Solve(Part::Two) => f.pad("part 2"),
+        }
+    }
+}
+
+// Wall times of all runs of a single phase
+#[derive(Debug, Clone)]
+pub struct PhaseTimings {
+    pub day: u32,
+    pub phase: Phase,
+    pub samples: Vec<Duration>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Statistics {
+    pub min: Duration,
+    pub median: Duration,
+    pub max: Duration,
+}
+
+impl Statistics {
+    pub fn from_samples(samples: &[Duration]) -> Statistics {
+        // For an even number of samples the median is the mean of both middle samples
+        let mut samples = samples.to_vec();
+        samples.sort();
+        let middle = samples.len() / 2;
+        let median = if samples.len().is_multiple_of(2) {
+            (samples[middle - 1] + samples[middle]) / 2
+        } else {
+            samples[middle]
+        };
+        Statistics {
+            min: samples[0],
+            median,
+            max: samples[samples.len() - 1],
+        }
+    }
+}
+
+impl PhaseTimings {
+    pub fn statistics(&self) -> Statistics {
+        Statistics::from_samples(&self.samples)
+    }
+}
+
+pub fn format_table(timings: &[PhaseTimings]) -> String {
+    // Create a table with the min, median and max time of every measured phase
+    let header = ("Day", "Phase", "Runs", "Min", "Median", "Max");
+    let rows = timings
+        .iter()
+        .map(|timing| {
+            let statistics = timing.statistics();
+            (
+                timing,
+                format_elapsed(statistics.min),
+                format_elapsed(statistics.median),
+                format_elapsed(statistics.max),
+            )
+        })
+        .collect::<Vec<_>>();
+    let width = rows
+        .iter()
+        .flat_map(|(_, min, median, max)| [min.len(), median.len(), max.len()])
+        .fold(header.4.len(), usize::max);
+
+    let mut table = format!(
+        "{:>3}  {:<6}  {:>4}  {:>width$}  {:>width$}  {:>width$}\n",
+        header.0,
+        header.1,
+        header.2,
+        header.3,
+        header.4,
+        header.5,
+        width = width
+    );
+    for (timing, min, median, max) in rows {
+        table.push_str(&format!(
+            "{:>3}  {:<6}  {:>4}  {:>width$}  {:>width$}  {:>width$}\n",
+            timing.day,
+            timing.phase,
+            timing.samples.len(),
+            min,
+            median,
+            max,
+            width = width
+        ));
+    }
+    table
+}
+
+pub const CSV_HEADER: &str = "revision,day,phase,runs,min_ms,median_ms,max_ms";
+
+pub fn format_csv_rows(revision: &str, timings: &[PhaseTimings]) -> String {
+    // One row per phase, the revision allows to compare the timings of different commits
+    timings
+        .iter()
+        .map(|timing| {
+            let statistics = timing.statistics();
+            format!(
+                "{},{},{},{},{:.6},{:.6},{:.6}\n",
+                revision,
+                timing.day,
+                timing.phase,
+                timing.samples.len(),
+                statistics.min.as_secs_f64() * 1000.0,
+                statistics.median.as_secs_f64() * 1000.0,
+                statistics.max.as_secs_f64() * 1000.0
+            )
+        })
+        .collect()
+}
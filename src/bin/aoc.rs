@@ -0,0 +1,669 @@
+use aoc2021::bench::{self, PhaseTimings};
+use aoc2021::days::day_24::{Alu, Day24, Register};
+use aoc2021::frames::FrameWriter;
+use aoc2021::input::InputSource;
+use aoc2021::log::{self, Level};
+use aoc2021::parameters::Parameter;
+use aoc2021::pool;
+use aoc2021::registry;
+use aoc2021::report::{self, PartResult};
+use aoc2021::verify::{self, Outcome};
+use aoc2021::{Part, Puzzle, Solution};
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::{self, Command};
+
+const USAGE: &str = "Usage:
+    aoc [-v | -vv | -vvv] <COMMAND>
+    aoc list
+    aoc run <DAY | all> [--part 1|2] [--format text|json] [--bench N [--csv PATH]] [--jobs N]
+            [--export-image PATH] [--export-frames DIR] [--export-trace PATH]
+            [--NAME VALUE]... [PATH | - | --example]
+    aoc check <DAY | all> [PATH | - | --example]
+    aoc detect <PATH | ->
+    aoc verify [ANSWERS_FILE]
+    aoc gen <DAY> --size N [--seed N]
+    aoc alu <PATH | - | --example> [INPUT]...
+
+The input of a day is read from inputs/day_N.txt unless a PATH, '-' for stdin
+or --example for the example of the puzzle description is given.
+--format json prints a record with the answer, time and day specific extra data per part.
+--bench times parsing and each part N times and reports min, median and max,
+--csv appends these timings together with the git revision to a CSV file.
+--jobs solves up to N days in parallel, the output keeps the order of the days.
+--export-image writes the final state of days 5, 9, 11, 13, 15, 20 and 25 as PBM, PGM or PPM image,
+with both parts the file name gets a _part_N suffix.
+--export-frames writes every step of days 6, 11, 13, 20, 23 and 25 as numbered files with an index.csv.
+--export-trace writes the position after every command of day 2 as CSV,
+with both parts the file name gets a _part_N suffix.
+--NAME VALUE overrides a parameter of the selected days for both parts, e.g. --days 100 for day 6,
+list shows the parameters of every day with their defaults.
+check validates the input of the selected days without solving them and lists every violation.
+detect lists the days whose input format accepts the given input.
+verify checks all answers recorded in answers.toml unless another file is given.
+gen prints a random input of a day, the same size and seed always create the same input.
+alu runs any program of day 24 with the given numbers as inputs of its inp instructions and prints the registers.
+-v logs the progress of the solvers to stderr, -vv intermediate steps and -vvv single operations.";
+
+enum CliError {
+    Usage(String),
+    Failed,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Format {
+    Text,
+    Json,
+}
+
+struct RunOptions {
+    days: Vec<&'static dyn Puzzle>,
+    parts: Vec<Part>,
+    format: Format,
+    bench_runs: Option<usize>,
+    csv_path: Option<PathBuf>,
+    jobs: usize,
+    image_path: Option<PathBuf>,
+    frames_directory: Option<PathBuf>,
+    trace_path: Option<PathBuf>,
+    overrides: Vec<(String, i64)>,
+    input_args: Vec<String>,
+}
+
+fn find_parameter(day: &dyn Puzzle, name: &str) -> Option<&'static Parameter> {
+    day.parameters()
+        .iter()
+        .find(|parameter| parameter.name == name)
+}
+
+fn parse_run_options(args: &[String]) -> Result<RunOptions, CliError> {
+    // First argument selects the days, --part the puzzle parts
+    // All remaining arguments select the input source
+    let (days, all_days) = match args.first().map(String::as_str) {
+        Some("all") => (registry::DAYS.to_vec(), true),
+        Some(day) => {
+            let day = day
+                .parse::<u32>()
+                .ok()
+                .and_then(registry::find_day)
+                .ok_or_else(|| CliError::Usage(format!("Unknown day '{}'", day)))?;
+            (vec![day], false)
+        }
+        None => return Err(CliError::Usage(String::from("Missing day to run"))),
+    };
+
+    let mut parts = vec![Part::One, Part::Two];
+    let mut format = Format::Text;
+    let mut bench_runs = None;
+    let mut csv_path = None;
+    let mut jobs = 1;
+    let mut image_path = None;
+    let mut frames_directory = None;
+    let mut trace_path = None;
+    let mut overrides = Vec::new();
+    let mut input_args = Vec::new();
+    let mut remaining_args = args[1..].iter();
+    while let Some(arg) = remaining_args.next() {
+        if arg == "--part" {
+            let part = remaining_args
+                .next()
+                .and_then(|part| Part::from_number(part))
+                .ok_or_else(|| CliError::Usage(String::from("--part has to be 1 or 2")))?;
+            parts = vec![part];
+        } else if arg == "--format" {
+            format = match remaining_args.next().map(String::as_str) {
+                Some("text") => Format::Text,
+                Some("json") => Format::Json,
+                _ => {
+                    return Err(CliError::Usage(String::from(
+                        "--format has to be text or json",
+                    )))
+                }
+            };
+        } else if arg == "--bench" {
+            let runs = remaining_args
+                .next()
+                .and_then(|runs| runs.parse::<usize>().ok())
+                .filter(|runs| *runs > 0)
+                .ok_or_else(|| {
+                    CliError::Usage(String::from("--bench needs a positive number of runs"))
+                })?;
+            bench_runs = Some(runs);
+        } else if arg == "--csv" {
+            let path = remaining_args
+                .next()
+                .ok_or_else(|| CliError::Usage(String::from("--csv needs a file path")))?;
+            csv_path = Some(PathBuf::from(path));
+        } else if arg == "--export-image" {
+            let path = remaining_args
+                .next()
+                .ok_or_else(|| CliError::Usage(String::from("--export-image needs a file path")))?;
+            image_path = Some(PathBuf::from(path));
+        } else if arg == "--export-frames" {
+            let directory = remaining_args.next().ok_or_else(|| {
+                CliError::Usage(String::from("--export-frames needs a directory"))
+            })?;
+            frames_directory = Some(PathBuf::from(directory));
+        } else if arg == "--export-trace" {
+            let path = remaining_args
+                .next()
+                .ok_or_else(|| CliError::Usage(String::from("--export-trace needs a file path")))?;
+            trace_path = Some(PathBuf::from(path));
+        } else if arg == "--jobs" {
+            jobs = remaining_args
+                .next()
+                .and_then(|jobs| jobs.parse::<usize>().ok())
+                .filter(|jobs| *jobs > 0)
+                .ok_or_else(|| {
+                    CliError::Usage(String::from("--jobs needs a positive number of workers"))
+                })?;
+        } else if let Some(name) = arg
+            .strip_prefix("--")
+            .filter(|name| days.iter().any(|day| find_parameter(*day, name).is_some()))
+        {
+            // Every selected day declaring the parameter has to accept the value
+            let value = remaining_args
+                .next()
+                .and_then(|value| value.parse::<i64>().ok())
+                .ok_or_else(|| CliError::Usage(format!("--{} needs a number", name)))?;
+            for parameter in days.iter().filter_map(|day| find_parameter(*day, name)) {
+                parameter.check(value).map_err(CliError::Usage)?;
+            }
+            overrides.push((String::from(name), value));
+        } else {
+            input_args.push(arg.clone());
+        }
+    }
+
+    if all_days && input_args.iter().any(|arg| arg != "--example") {
+        return Err(CliError::Usage(String::from(
+            "Only --example can be used to select the input of all days",
+        )));
+    }
+
+    if csv_path.is_some() && bench_runs.is_none() {
+        return Err(CliError::Usage(String::from(
+            "--csv can only be used together with --bench",
+        )));
+    }
+
+    if image_path.is_some() && (all_days || bench_runs.is_some()) {
+        return Err(CliError::Usage(String::from(
+            "--export-image needs a single day and can not be used together with --bench",
+        )));
+    }
+
+    if frames_directory.is_some() && (all_days || bench_runs.is_some()) {
+        return Err(CliError::Usage(String::from(
+            "--export-frames needs a single day and can not be used together with --bench",
+        )));
+    }
+
+    if trace_path.is_some() && (all_days || bench_runs.is_some()) {
+        return Err(CliError::Usage(String::from(
+            "--export-trace needs a single day and can not be used together with --bench",
+        )));
+    }
+
+    if jobs > 1 && bench_runs.is_some() {
+        return Err(CliError::Usage(String::from(
+            "--jobs can not be used together with --bench, parallel days distort the timings",
+        )));
+    }
+
+    if format == Format::Json && bench_runs.is_some() {
+        return Err(CliError::Usage(String::from(
+            "--format json can not be used together with --bench",
+        )));
+    }
+
+    Ok(RunOptions {
+        days,
+        parts,
+        format,
+        bench_runs,
+        csv_path,
+        jobs,
+        image_path,
+        frames_directory,
+        trace_path,
+        overrides,
+        input_args,
+    })
+}
+
+// Outcome of a single day, collected from the workers and reported in the order of the days
+enum DayOutcome {
+    Solved(Vec<PartResult>),
+    Benched(Vec<PhaseTimings>),
+    Failed(String),
+}
+
+fn part_path(path: &Path, part: Part, parts: &[Part]) -> PathBuf {
+    // With both parts the part number is added to the file name
+    if parts.len() > 1 {
+        let mut file_name = path.file_stem().unwrap_or_default().to_os_string();
+        file_name.push(format!("_part_{}", part.number()));
+        if let Some(extension) = path.extension() {
+            file_name.push(".");
+            file_name.push(extension);
+        }
+        path.with_file_name(file_name)
+    } else {
+        path.to_path_buf()
+    }
+}
+
+fn export_images(
+    day: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    options: &RunOptions,
+    path: &Path,
+) -> Result<(), String> {
+    // Write an image per part
+    for part in parts {
+        let image = day
+            .render_image(input, *part, &options.overrides)
+            .map_err(|error| error.to_string())?
+            .ok_or_else(|| format!("Day {} has no image to export", day.day()))?;
+        let part_path = part_path(path, *part, parts);
+        File::create(&part_path)
+            .and_then(|mut file| image.write(&mut file))
+            .map_err(|error| format!("Unable to write {}: {}", part_path.display(), error))?;
+    }
+    Ok(())
+}
+
+fn export_frames(
+    day: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    options: &RunOptions,
+    directory: &Path,
+) -> Result<(), String> {
+    // Frames of all parts share the directory and its index
+    // The directory is only created once the day emits its first frame
+    let mut writer: Option<FrameWriter> = None;
+    let mut write_result = Ok(());
+    for part in parts {
+        let mut number = 0;
+        day.export_frames(input, *part, &options.overrides, &mut |frame| {
+            if write_result.is_ok() {
+                write_result = match &mut writer {
+                    Some(writer) => Ok(writer),
+                    None => FrameWriter::create(directory).map(|created| writer.insert(created)),
+                }
+                .and_then(|writer| writer.write(*part, number, &frame));
+            }
+            number += 1;
+        })
+        .map_err(|error| error.to_string())?;
+        if number == 0 {
+            return Err(format!("Day {} has no frames to export", day.day()));
+        }
+    }
+    write_result.map_err(|error| {
+        format!(
+            "Unable to write frames to {}: {}",
+            directory.display(),
+            error
+        )
+    })
+}
+
+fn export_traces(
+    day: &dyn Puzzle,
+    input: &str,
+    parts: &[Part],
+    options: &RunOptions,
+    path: &Path,
+) -> Result<(), String> {
+    // Write a CSV trace per part
+    for part in parts {
+        let trace = day
+            .export_trace(input, *part, &options.overrides)
+            .map_err(|error| error.to_string())?
+            .ok_or_else(|| format!("Day {} has no trace to export", day.day()))?;
+        let part_path = part_path(path, *part, parts);
+        fs::write(&part_path, trace)
+            .map_err(|error| format!("Unable to write {}: {}", part_path.display(), error))?;
+    }
+    Ok(())
+}
+
+fn run_day(day: &dyn Puzzle, options: &RunOptions) -> DayOutcome {
+    // Days without a readable or well formed input are reported and skipped
+    let source = match InputSource::from_args(day.day(), options.input_args.clone()) {
+        Ok(source) => source,
+        Err(error) => return DayOutcome::Failed(format!("Day {}: {}", day.day(), error)),
+    };
+    // Parts the day does not have are skipped, the last day has only one part
+    let parts = options
+        .parts
+        .iter()
+        .cloned()
+        .filter(|part| day.parts().contains(part))
+        .collect::<Vec<Part>>();
+    // An input that is only solved is streamed to the day, so day 1 does not keep huge logs in memory
+    if options.bench_runs.is_none()
+        && options.image_path.is_none()
+        && options.frames_directory.is_none()
+        && options.trace_path.is_none()
+    {
+        let mut reader = match source.open(day.example()) {
+            Ok(reader) => reader,
+            Err(error) => return DayOutcome::Failed(format!("Day {}: {}", day.day(), error)),
+        };
+        return match day.solve_reader(&mut reader, &parts, &options.overrides) {
+            Ok(results) => DayOutcome::Solved(results),
+            Err(error) => DayOutcome::Failed(error.to_string()),
+        };
+    }
+    let input = match source.read(day.example()) {
+        Ok(input) => input,
+        Err(error) => return DayOutcome::Failed(format!("Day {}: {}", day.day(), error)),
+    };
+    if let Some(runs) = options.bench_runs {
+        return match day.bench_parts(&input, &parts, &options.overrides, runs) {
+            Ok(timings) => DayOutcome::Benched(timings),
+            Err(error) => DayOutcome::Failed(error.to_string()),
+        };
+    }
+    if let Some(path) = &options.image_path {
+        if let Err(error) = export_images(day, &input, &parts, options, path) {
+            return DayOutcome::Failed(error);
+        }
+    }
+    if let Some(directory) = &options.frames_directory {
+        if let Err(error) = export_frames(day, &input, &parts, options, directory) {
+            return DayOutcome::Failed(error);
+        }
+    }
+    if let Some(path) = &options.trace_path {
+        if let Err(error) = export_traces(day, &input, &parts, options, path) {
+            return DayOutcome::Failed(error);
+        }
+    }
+    match day.solve_parts(&input, &parts, &options.overrides) {
+        Ok(results) => DayOutcome::Solved(results),
+        Err(error) => DayOutcome::Failed(error.to_string()),
+    }
+}
+
+fn run(options: RunOptions) -> Result<(), CliError> {
+    // Solve the selected parts of all selected days on the worker pool and print a summary table
+    // Each part is timed on its own worker, so the times of a day do not include waiting for other days
+    // A day that panics is reported as failed, the other days keep their results
+    let outcomes = pool::map(&options.days, options.jobs, |day| run_day(*day, &options))
+        .into_iter()
+        .zip(&options.days)
+        .map(|(outcome, day)| {
+            outcome.unwrap_or_else(|message| {
+                DayOutcome::Failed(format!("Day {} panicked: {}", day.day(), message))
+            })
+        });
+    let mut results = Vec::new();
+    let mut timings = Vec::new();
+    let mut failed = false;
+    for outcome in outcomes {
+        match outcome {
+            DayOutcome::Solved(day_results) => results.extend(day_results),
+            DayOutcome::Benched(day_timings) => timings.extend(day_timings),
+            DayOutcome::Failed(error) => {
+                eprintln!("{}", error);
+                failed = true;
+            }
+        }
+    }
+
+    match options.format {
+        Format::Text if !results.is_empty() => {
+            print!("{}", report::format_table(&results));
+            print!("{}", report::format_reports(&results));
+        }
+        Format::Text => {}
+        Format::Json => print!("{}", report::format_json(&results)),
+    }
+    if !timings.is_empty() {
+        print!("{}", bench::format_table(&timings));
+        if let Some(path) = &options.csv_path {
+            if let Err(error) = append_csv(path, &timings) {
+                eprintln!("Unable to write {}: {}", path.display(), error);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        Err(CliError::Failed)
+    } else {
+        Ok(())
+    }
+}
+
+fn current_revision() -> String {
+    // Commit of the working tree, marked as dirty if there are uncommitted changes
+    Command::new("git")
+        .args(["describe", "--always", "--dirty"])
+        .output()
+        .ok()
+        .filter(|output| output.status.success())
+        .and_then(|output| String::from_utf8(output.stdout).ok())
+        .map(|revision| String::from(revision.trim()))
+        .unwrap_or_else(|| String::from("unknown"))
+}
+
+fn append_csv(path: &Path, timings: &[PhaseTimings]) -> std::io::Result<()> {
+    // Timings are appended to keep the history of earlier runs, the header is only written once
+    let write_header = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+    if write_header {
+        writeln!(file, "{}", bench::CSV_HEADER)?;
+    }
+    write!(
+        file,
+        "{}",
+        bench::format_csv_rows(&current_revision(), timings)
+    )
+}
+
+fn verify_answers(args: &[String]) -> Result<(), CliError> {
+    // Check the recorded answers and fail if any of them does not match
+    let path = match args {
+        [] => verify::default_answers_path(),
+        [path] => PathBuf::from(path),
+        _ => {
+            return Err(CliError::Usage(String::from(
+                "Only one answers file is allowed",
+            )))
+        }
+    };
+    let answers = verify::load_answers(&path).map_err(|error| {
+        eprintln!("{}", error);
+        CliError::Failed
+    })?;
+    let verifications = verify::verify(&answers);
+    print!("{}", verify::format_report(&verifications));
+    if verifications
+        .iter()
+        .any(|verification| matches!(verification.outcome, Outcome::Mismatch(_)))
+    {
+        Err(CliError::Failed)
+    } else {
+        Ok(())
+    }
+}
+
+fn generate_input(args: &[String]) -> Result<(), CliError> {
+    // Print a synthetic input, the size is mandatory as its meaning differs per day
+    let day = match args.first() {
+        Some(day) => day
+            .parse::<u32>()
+            .ok()
+            .and_then(registry::find_day)
+            .ok_or_else(|| CliError::Usage(format!("Unknown day '{}'", day)))?,
+        None => return Err(CliError::Usage(String::from("Missing day to generate"))),
+    };
+
+    let mut size = None;
+    let mut seed = 0;
+    let mut remaining_args = args[1..].iter();
+    while let Some(arg) = remaining_args.next() {
+        if arg == "--size" {
+            let value = remaining_args
+                .next()
+                .and_then(|size| size.parse::<usize>().ok())
+                .filter(|size| *size > 0)
+                .ok_or_else(|| CliError::Usage(String::from("--size needs a positive number")))?;
+            size = Some(value);
+        } else if arg == "--seed" {
+            seed = remaining_args
+                .next()
+                .and_then(|seed| seed.parse::<u64>().ok())
+                .ok_or_else(|| CliError::Usage(String::from("--seed needs a number")))?;
+        } else {
+            return Err(CliError::Usage(format!("Unexpected argument '{}'", arg)));
+        }
+    }
+    let size = size.ok_or_else(|| CliError::Usage(String::from("Missing --size")))?;
+
+    print!("{}", day.generate(size, seed));
+    Ok(())
+}
+
+fn check_inputs(args: &[String]) -> Result<(), CliError> {
+    // Validate the inputs like run does before solving, all violations of a day are listed at once
+    let days = match args.first().map(String::as_str) {
+        Some("all") => registry::DAYS.to_vec(),
+        Some(day) => vec![day
+            .parse::<u32>()
+            .ok()
+            .and_then(registry::find_day)
+            .ok_or_else(|| CliError::Usage(format!("Unknown day '{}'", day)))?],
+        None => return Err(CliError::Usage(String::from("Missing day to check"))),
+    };
+    let mut failed = false;
+    for day in days {
+        let result = InputSource::from_args(day.day(), args[1..].to_vec())
+            .and_then(|source| source.read(day.example()));
+        match result.map(|input| day.validate(&input)) {
+            Ok(Ok(())) => println!("Day {}: valid input", day.day()),
+            Ok(Err(error)) => {
+                eprintln!("{}", error);
+                failed = true;
+            }
+            Err(error) => {
+                eprintln!("Day {}: {}", day.day(), error);
+                failed = true;
+            }
+        }
+    }
+    if failed {
+        Err(CliError::Failed)
+    } else {
+        Ok(())
+    }
+}
+
+fn detect_days(args: &[String]) -> Result<(), CliError> {
+    // Some formats are shared by several days, e.g. a number per line, so all matching days are listed
+    let source = match args {
+        [source] => InputSource::from_args(0, [source.clone()])
+            .ok()
+            .filter(|source| *source != InputSource::Example)
+            .ok_or_else(|| CliError::Usage(format!("Unexpected argument '{}'", source)))?,
+        [] => return Err(CliError::Usage(String::from("Missing input to detect"))),
+        _ => return Err(CliError::Usage(String::from("Only one input is allowed"))),
+    };
+    let input = source.read("").map_err(|error| {
+        eprintln!("{}", error);
+        CliError::Failed
+    })?;
+    let matching_days = registry::DAYS
+        .iter()
+        .filter(|day| day.validate(&input).is_ok())
+        .collect::<Vec<_>>();
+    if matching_days.is_empty() {
+        eprintln!("The input does not match the format of any day");
+        return Err(CliError::Failed);
+    }
+    for day in matching_days {
+        println!("{:>3}  {}", day.day(), day.title());
+    }
+    Ok(())
+}
+
+fn run_alu(args: &[String]) -> Result<(), CliError> {
+    // Run a program of day 24 on the interpreter, unlike run it does not have to be a MONAD program
+    let (source, inputs) = match args {
+        [source, inputs @ ..] => (
+            InputSource::from_args(Day24::DAY, [source.clone()])
+                .map_err(|error| CliError::Usage(error.to_string()))?,
+            inputs
+                .iter()
+                .map(|input| {
+                    input
+                        .parse::<i64>()
+                        .map_err(|_| CliError::Usage(format!("Invalid ALU input '{}'", input)))
+                })
+                .collect::<Result<Vec<i64>, CliError>>()?,
+        ),
+        [] => return Err(CliError::Usage(String::from("Missing ALU program"))),
+    };
+    let alu = source
+        .read(Day24::EXAMPLE)
+        .map_err(|error| error.to_string())
+        .and_then(|program| Day24::parse(&program).map_err(|error| error.to_string()))
+        .and_then(|program| Alu::run(&program, &inputs).map_err(|error| error.to_string()))
+        .map_err(|error| {
+            eprintln!("{}", error);
+            CliError::Failed
+        })?;
+    for register in [Register::W, Register::X, Register::Y, Register::Z] {
+        println!("{} = {}", register, alu.get(register));
+    }
+    Ok(())
+}
+
+fn list() -> Result<(), CliError> {
+    // Parameters are listed below their day with the defaults of both parts
+    for day in registry::DAYS.iter() {
+        println!("{:>3}  {}", day.day(), day.title());
+        for parameter in day.parameters() {
+            println!("       {:<22}{}", parameter, parameter.description);
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    // -v, -vv and -vvv raise the log level of the solvers, they are accepted anywhere in the arguments
+    let (verbosity_flags, args): (Vec<String>, Vec<String>) =
+        env::args().skip(1).partition(|arg| {
+            arg.len() > 1 && arg.starts_with('-') && arg[1..].chars().all(|c| c == 'v')
+        });
+    log::set_level(Level::from_verbosity(
+        verbosity_flags.iter().map(|flag| flag.len() - 1).sum(),
+    ));
+    let result = match args.first().map(String::as_str) {
+        Some("list") => list(),
+        Some("run") => parse_run_options(&args[1..]).and_then(run),
+        Some("check") => check_inputs(&args[1..]),
+        Some("detect") => detect_days(&args[1..]),
+        Some("verify") => verify_answers(&args[1..]),
+        Some("gen") => generate_input(&args[1..]),
+        Some("alu") => run_alu(&args[1..]),
+        Some(command) => Err(CliError::Usage(format!("Unknown command '{}'", command))),
+        None => Err(CliError::Usage(String::from("Missing command"))),
+    };
+    match result {
+        Ok(()) => {}
+        Err(CliError::Usage(message)) => {
+            eprintln!("{}\n\n{}", message, USAGE);
+            process::exit(2);
+        }
+        Err(CliError::Failed) => process::exit(1),
+    }
+}
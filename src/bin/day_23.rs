@@ -0,0 +1,6 @@
+// Solves day 23 like `aoc run 23`, see day_binary::run for the arguments
+mod day_binary;
+
+fn main() {
+    day_binary::run(23);
+}
@@ -0,0 +1,6 @@
+// Solves day 24 like `aoc run 24`, see day_binary::run for the arguments
+mod day_binary;
+
+fn main() {
+    day_binary::run(24);
+}
@@ -0,0 +1,27 @@
+use aoc2021::registry::{self, DayBinaryError};
+use aoc2021::report;
+use std::env;
+use std::process;
+
+pub fn run(number: u32) {
+    // Entry point of the per-day binaries, prints the results of all parts of a day like `aoc run N`
+    // Exit with a readable message instead of a panic if the input can not be read or solved
+    match registry::run_day_binary(number, env::args().skip(1)) {
+        Ok(results) => {
+            print!("{}", report::format_table(&results));
+            print!("{}", report::format_reports(&results));
+        }
+        Err(error @ DayBinaryError::Input(_)) => {
+            eprintln!("{}", error);
+            eprintln!(
+                "Usage: day_{} [PATH | - | --example] (default: inputs/day_{}.txt)",
+                number, number
+            );
+            process::exit(1);
+        }
+        Err(error) => {
+            eprintln!("{}", error);
+            process::exit(1);
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// Detection of repeating states in simulations that may never reach their goal
+// Cycles are found with Brent's algorithm, which only keeps a single earlier state
+#[derive(Debug, Clone)]
+pub struct CycleDetector<T> {
+    checkpoint: T,
+    power: usize,
+    length: usize,
+}
+
+impl<T: Clone + PartialEq> CycleDetector<T> {
+    pub fn new(start: &T) -> CycleDetector<T> {
+        CycleDetector {
+            checkpoint: start.clone(),
+            power: 1,
+            length: 0,
+        }
+    }
+
+    pub fn cycle_length(&mut self, state: &T) -> Option<usize> {
+        // Called with every state after the start, the length of the cycle once a state repeats
+        // The checkpoint moves ahead after powers of two, so the cycle is found within twice its start and length
+        self.length += 1;
+        if *state == self.checkpoint {
+            return Some(self.length);
+        }
+        if self.length == self.power {
+            self.checkpoint = state.clone();
+            self.power *= 2;
+            self.length = 0;
+        }
+        None
+    }
+}
@@ -0,0 +1,438 @@
+use crate::generate::Rng;
+use crate::json::Json;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::report::{PartResult, Report};
+use crate::solution::{self, Answer, Part, Solution, SolveError};
+use std::collections::{BTreeMap, VecDeque};
+use std::fmt;
+use std::io::{self, BufRead};
+use std::time::Instant;
+
+// Example depth measurements from the puzzle description, used with --example
+const EXAMPLE: &str = "199
+200
+208
+210
+200
+207
+240
+269
+260
+263";
+
+fn parse_depth(line: &InputLine) -> Result<i32, ParseError> {
+    line.parse(line.text.trim(), "depth measurement")
+}
+
+fn parse_input_data(input_data: &str) -> Result<Vec<i32>, InvalidInput> {
+    // Get the numeric depth measurement from each input line
+    parse::parse_lines(Day1::DAY, input_data, |line| parse_depth(&line))
+}
+
+// Malformed lines reported by a sweep, a log of garbage is rejected without collecting an error per line
+const MAX_VIOLATIONS: usize = 100;
+
+// Counts increasing window sums of depths that arrive one at a time
+// Consecutive windows share all but one measurement, so the sum increases exactly
+// if the new measurement is larger than the one leaving the window
+// Only the measurements of the current window are kept, memory does not grow with the input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SonarSweep {
+    window_size: usize,
+    window: VecDeque<i32>,
+    measurements: u64,
+    increases: u64,
+}
+
+impl SonarSweep {
+    pub fn new(window_size: usize) -> SonarSweep {
+        // The window grows with the measurements, a huge window size does not allocate up front
+        SonarSweep {
+            window_size: window_size.max(1),
+            window: VecDeque::new(),
+            measurements: 0,
+            increases: 0,
+        }
+    }
+
+    pub fn push(&mut self, depth: i32) {
+        self.window.push_back(depth);
+        self.measurements += 1;
+        if self.window.len() > self.window_size {
+            let leaving = self.window.pop_front().unwrap();
+            self.increases += (depth > leaving) as u64;
+        }
+    }
+
+    pub fn measurements(&self) -> u64 {
+        self.measurements
+    }
+
+    pub fn increases(&self) -> u64 {
+        self.increases
+    }
+}
+
+#[derive(Debug)]
+pub enum SweepError {
+    Unreadable(io::Error),
+    Invalid(InvalidInput),
+}
+
+impl fmt::Display for SweepError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SweepError::Unreadable(error) => write!(f, "Unable to read depths: {}", error),
+            SweepError::Invalid(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for SweepError {}
+
+impl From<SweepError> for SolveError {
+    fn from(error: SweepError) -> SolveError {
+        match error {
+            SweepError::Unreadable(error) => SolveError::Unreadable {
+                day: Day1::DAY,
+                reason: error.to_string(),
+            },
+            SweepError::Invalid(error) => SolveError::Invalid(error),
+        }
+    }
+}
+
+pub fn sweep_reader(reader: impl BufRead, window_size: usize) -> Result<SonarSweep, SweepError> {
+    let mut sweeps = [SonarSweep::new(window_size)];
+    sweep_depths(reader, &mut sweeps)?;
+    let [sweep] = sweeps;
+    Ok(sweep)
+}
+
+fn sweep_depths(reader: impl BufRead, sweeps: &mut [SonarSweep]) -> Result<(), SweepError> {
+    // Read the depths line by line from any source, e.g. a sonar log too large to keep in memory
+    // A single line buffer is reused, malformed lines are collected and reported together at the end
+    // Reading stops at the first MAX_VIOLATIONS malformed lines, the rest of the input is not checked
+    let mut reader = reader;
+    let mut violations = Vec::new();
+    let mut text = String::new();
+    for number in 1.. {
+        text.clear();
+        if reader
+            .read_line(&mut text)
+            .map_err(SweepError::Unreadable)?
+            == 0
+        {
+            break;
+        }
+        let line = InputLine {
+            day: Day1::DAY,
+            number,
+            text: text
+                .strip_suffix('\n')
+                .unwrap_or(&text)
+                .trim_end_matches('\r'),
+        };
+        match parse_depth(&line) {
+            Ok(depth) => sweeps.iter_mut().for_each(|sweep| sweep.push(depth)),
+            Err(error) => violations.push(error),
+        }
+        if violations.len() == MAX_VIOLATIONS {
+            break;
+        }
+    }
+    InvalidInput::from_violations(violations).map_err(SweepError::Invalid)
+}
+
+fn count_increasing_window_sums(numbers: &[i32], window_size: usize) -> u64 {
+    // Consecutive windows share all but one measurement, so each pair of windows spans window_size + 1 measurements
+    // The sum increases exactly if the last of them is larger than the first, the same rule as the sweep
+    numbers
+        .windows(window_size + 1)
+        .filter(|pair| pair[window_size] > pair[0])
+        .count() as u64
+}
+
+// Consecutive measurements from start to end, both inclusive
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Run {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Run {
+    pub fn measurements(&self) -> usize {
+        self.end - self.start + 1
+    }
+}
+
+// Shape of a depth series beyond the number of increases
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DepthProfile {
+    pub longest_increasing_run: Option<Run>,
+    pub longest_decreasing_run: Option<Run>,
+    // Index of the measurement after the jump and the change of depth, the largest change in either direction
+    pub largest_jump: Option<(usize, i64)>,
+    // Runs of equal measurements and the number of steps without a change of depth
+    pub plateaus: usize,
+    pub flat_steps: usize,
+    pub delta_histogram: BTreeMap<i64, usize>,
+    // Number of increasing window sums for each window size from 1 up to the largest size
+    pub increases_per_window: Vec<(usize, usize)>,
+}
+
+fn runs(deltas: &[i64], belongs_to_run: impl Fn(i64) -> bool) -> Vec<Run> {
+    // Maximal runs of measurements whose consecutive deltas all belong to the run
+    let mut runs: Vec<Run> = Vec::new();
+    for (i, delta) in deltas.iter().enumerate() {
+        if !belongs_to_run(*delta) {
+            continue;
+        }
+        match runs.last_mut() {
+            Some(run) if run.end == i => run.end = i + 1,
+            _ => runs.push(Run {
+                start: i,
+                end: i + 1,
+            }),
+        }
+    }
+    runs
+}
+
+fn longest_run(runs: &[Run]) -> Option<Run> {
+    // The first of several equally long runs
+    runs.iter()
+        .rev()
+        .max_by_key(|run| run.measurements())
+        .cloned()
+}
+
+pub fn depth_profile(numbers: &[i32], max_window_size: usize) -> DepthProfile {
+    let deltas = numbers
+        .windows(2)
+        .map(|pair| pair[1] as i64 - pair[0] as i64)
+        .collect::<Vec<i64>>();
+    let plateaus = runs(&deltas, |delta| delta == 0);
+    let mut delta_histogram = BTreeMap::new();
+    for delta in deltas.iter() {
+        *delta_histogram.entry(*delta).or_insert(0) += 1;
+    }
+    DepthProfile {
+        longest_increasing_run: longest_run(&runs(&deltas, |delta| delta > 0)),
+        longest_decreasing_run: longest_run(&runs(&deltas, |delta| delta < 0)),
+        largest_jump: deltas
+            .iter()
+            .enumerate()
+            .rev()
+            .max_by_key(|(_, delta)| delta.abs())
+            .map(|(i, delta)| (i + 1, *delta)),
+        plateaus: plateaus.len(),
+        flat_steps: plateaus.iter().map(|run| run.measurements() - 1).sum(),
+        delta_histogram,
+        increases_per_window: (1..=max_window_size)
+            .map(|window_size| {
+                let increases = count_increasing_window_sums(numbers, window_size);
+                (window_size, increases as usize)
+            })
+            .collect(),
+    }
+}
+
+impl From<&DepthProfile> for Json {
+    fn from(profile: &DepthProfile) -> Json {
+        let run = |run: Option<Run>| run.map_or(Json::Null, |run| (run.start, run.end).into());
+        Json::object()
+            .with(
+                "longest_increasing_run",
+                run(profile.longest_increasing_run),
+            )
+            .with(
+                "longest_decreasing_run",
+                run(profile.longest_decreasing_run),
+            )
+            .with(
+                "largest_jump",
+                profile
+                    .largest_jump
+                    .map_or(Json::Null, |(index, delta)| (index, delta).into()),
+            )
+            .with("plateaus", profile.plateaus)
+            .with("flat_steps", profile.flat_steps)
+            .with(
+                "delta_histogram",
+                profile
+                    .delta_histogram
+                    .iter()
+                    .map(|(delta, count)| (*delta, *count))
+                    .collect::<Vec<(i64, usize)>>(),
+            )
+            .with("increases_per_window", profile.increases_per_window.clone())
+    }
+}
+
+impl fmt::Display for DepthProfile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let run = |run: Option<Run>| {
+            run.map_or(String::from("none"), |run| {
+                format!(
+                    "{} measurements from {} to {}",
+                    run.measurements(),
+                    run.start,
+                    run.end
+                )
+            })
+        };
+        writeln!(
+            f,
+            "Longest increasing run  {}",
+            run(self.longest_increasing_run)
+        )?;
+        writeln!(
+            f,
+            "Longest decreasing run  {}",
+            run(self.longest_decreasing_run)
+        )?;
+        if let Some((index, delta)) = self.largest_jump {
+            writeln!(
+                f,
+                "Largest jump            {:+} to measurement {}",
+                delta, index
+            )?;
+        }
+        writeln!(
+            f,
+            "Plateaus                {} with {} flat steps",
+            self.plateaus, self.flat_steps
+        )?;
+        writeln!(f, "Delta  Count")?;
+        for (delta, count) in self.delta_histogram.iter() {
+            writeln!(f, "{:>+5}  {}", delta, count)?;
+        }
+        writeln!(f, "Window  Increases")?;
+        for (window_size, increases) in self.increases_per_window.iter() {
+            writeln!(f, "{:>6}  {}", window_size, increases)?;
+        }
+        Ok(())
+    }
+}
+
+fn generate_depth_measurements(rng: &mut Rng, size: usize) -> String {
+    // Random walk of mostly increasing depths, the sea floor slopes down
+    // The walk is reflected at the surface and the largest depth, so long inputs stay parsable
+    let max_depth = i32::MAX as i64;
+    let mut depth = rng.range(100..=200);
+    (0..size)
+        .map(|_| {
+            let measurement = format!("{}\n", depth);
+            depth += rng.range(-10..=30);
+            if depth < 0 {
+                depth = -depth;
+            } else if depth > max_depth {
+                depth = 2 * max_depth - depth;
+            }
+            measurement
+        })
+        .collect()
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u32 = 1;
+    const TITLE: &'static str = "Sonar Sweep";
+    const EXAMPLE: &'static str = EXAMPLE;
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter {
+            name: "window",
+            description: "Number of measurements summed up before comparing",
+            defaults: [1, 3],
+            minimum: 1,
+            maximum: i64::MAX,
+        },
+        Parameter {
+            name: "profile",
+            description: "Largest window size of the depth profile of part 1, 0 skips the profile",
+            defaults: [0, 0],
+            minimum: 0,
+            maximum: 1000,
+        },
+    ];
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_depth_measurements(rng, size)
+    }
+
+    fn part_one(numbers: &Vec<i32>, parameters: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(count_increasing_window_sums(
+            numbers,
+            parameters.get("window") as usize,
+        )))
+    }
+
+    fn part_two(numbers: &Vec<i32>, parameters: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(count_increasing_window_sums(
+            numbers,
+            parameters.get("window") as usize,
+        )))
+    }
+
+    fn report(numbers: &Vec<i32>, part: Part, parameters: &Parameters) -> Option<Report> {
+        // The profile belongs to part 1, a largest window size of 0 skips it
+        match (part, parameters.get("profile")) {
+            (Part::Two, _) | (_, 0) => None,
+            (Part::One, max_window_size) => {
+                let profile = depth_profile(numbers, max_window_size as usize);
+                Some(Report {
+                    title: String::from("Depth profile"),
+                    text: profile.to_string(),
+                    data: Json::from(&profile),
+                })
+            }
+        }
+    }
+
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+    ) -> Result<Vec<PartResult>, SolveError> {
+        let parameters = parts
+            .iter()
+            .map(|part| Parameters::new(Day1::PARAMETERS, *part, overrides))
+            .collect::<Vec<Parameters>>();
+        // The depth profile needs all measurements at once, so the input is read completely for it
+        if parts
+            .iter()
+            .zip(&parameters)
+            .any(|(part, parameters)| *part == Part::One && parameters.get("profile") > 0)
+        {
+            return solution::solve_read_input::<Day1>(reader, parts, overrides);
+        }
+        // Both parts are counted in a single pass over the lines, each part is given the time of the whole pass
+        let mut sweeps = parameters
+            .iter()
+            .map(|parameters| SonarSweep::new(parameters.get("window") as usize))
+            .collect::<Vec<SonarSweep>>();
+        let start = Instant::now();
+        sweep_depths(reader, &mut sweeps)?;
+        let elapsed = start.elapsed();
+        Ok(parts
+            .iter()
+            .zip(sweeps)
+            .map(|(part, sweep)| PartResult {
+                day: Day1::DAY,
+                part: *part,
+                answer: Answer::new(sweep.increases()),
+                elapsed,
+                report: None,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,213 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Solution};
+
+// Example navigation subsystem lines from the puzzle description, used with --example
+const EXAMPLE: &str = "[({(<(())[]>[[{[]{<()<>>
+[(()[<>])]({[<{<<[]>>(
+{([(<{}[<>[]}>{[]{[(<()>
+(((({<>}<{<{<>}{[]{[]{}
+[[<[([]))<([[{}[[()]]]
+[{[{({}]{}}([{[{{{}}([]
+{<[[]]>}<{[{[{[]{()[[[]
+[<(<(<(<{}))><([]([]()
+<{([([[(<>()){}]>(<<{{
+<{([{{}}[<[[[<>{}]]]>[]]";
+
+fn is_incomplete(line: &InputLine, chunk_chars: &[char]) -> Result<bool, ParseError> {
+    // Check the chunks up to the first illegal char, the rest of a corrupted line is never checked
+    // A closing char without an open chunk is neither corrupted nor incomplete
+    let mut chunk_stack = Vec::new();
+    for (index, c) in chunk_chars.iter().cloned().enumerate() {
+        match (c, chunk_stack.last()) {
+            ('(' | '[' | '{' | '<', _) => chunk_stack.push(c),
+            (_, None) => {
+                return Err(line.error(
+                    &line.text[index..index + 1],
+                    "Closing chunk char without an open chunk",
+                ))
+            }
+            (')', Some('(')) | (']', Some('[')) | ('}', Some('{')) | ('>', Some('<')) => {
+                chunk_stack.pop();
+            }
+            _ => return Ok(false),
+        }
+    }
+    Ok(!chunk_stack.is_empty())
+}
+
+fn parse_chunk_line(line: InputLine) -> Result<(Vec<char>, bool), InvalidInput> {
+    let chunk_chars = parse::collect_all(line.text.char_indices().map(|(index, c)| {
+        if "()[]{}<>".contains(c) {
+            Ok(c)
+        } else {
+            Err(line.error(
+                &line.text[index..index + c.len_utf8()],
+                "Invalid chunk char",
+            ))
+        }
+    }))?;
+    let incomplete = is_incomplete(&line, &chunk_chars)?;
+    Ok((chunk_chars, incomplete))
+}
+
+fn parse_input_data(input_lines: &str) -> Result<Vec<Vec<char>>, InvalidInput> {
+    // Split input into lists of bracket chars
+    // The middle autocomplete score needs at least one incomplete line
+    let lines = parse::collect_all(parse::lines(Day10::DAY, input_lines).map(parse_chunk_line))?;
+    if !lines.iter().any(|(_, incomplete)| *incomplete) {
+        return Err(parse::end_of_input(Day10::DAY, input_lines)
+            .error("", "Expected at least one incomplete line")
+            .into());
+    }
+    Ok(lines
+        .into_iter()
+        .map(|(chunk_chars, _)| chunk_chars)
+        .collect())
+}
+
+fn check_line_chunks(line: &[char]) -> Result<Vec<char>, char> {
+    // Parse line chunks into matching bracket stack
+    // Return Ok(empty stack) in case of a valid line
+    // Return Ok(remaining stack) in case an incomplete line
+    // Return Err(Illegal character) in case of a corrupted line
+    let mut chunk_stack = Vec::new();
+    for c in line.iter().cloned() {
+        match c {
+            '(' | '[' | '{' | '<' => chunk_stack.push(c),
+            _ => match (chunk_stack.last().unwrap(), c) {
+                ('(', ')') | ('[', ']') | ('{', '}') | ('<', '>') => {
+                    chunk_stack.pop();
+                }
+                _ => {
+                    return Err(c);
+                }
+            },
+        };
+    }
+    Ok(chunk_stack)
+}
+
+fn calculate_syntax_error_score(line_chunks: &[Result<Vec<char>, char>]) -> i64 {
+    // Calculate syntax error score based on the illegal characters
+    line_chunks
+        .iter()
+        .map(|result| match result {
+            Err(')') => 3,
+            Err(']') => 57,
+            Err('}') => 1197,
+            Err('>') => 25137,
+            _ => 0,
+        })
+        .sum()
+}
+
+fn calculate_line_autocomplete_score(line_stack: Vec<char>) -> i64 {
+    // Calculate the autocomplete score of the line based on the characters the remaining stack
+    line_stack.iter().rev().fold(0, |autocomplete_score, c| {
+        autocomplete_score * 5
+            + match c {
+                '(' => 1,
+                '[' => 2,
+                '{' => 3,
+                '<' => 4,
+                _ => 0,
+            }
+    })
+}
+
+fn calculate_autocomplete_score(line_chunks: &[Result<Vec<char>, char>]) -> i64 {
+    // Search for invalid lines and calculate the autocomplete score for each
+    let mut line_completion_scores = line_chunks
+        .iter()
+        .cloned()
+        .map(|line_result| line_result.unwrap_or_default())
+        .filter(|line_result| !line_result.is_empty())
+        .map(calculate_line_autocomplete_score)
+        .collect::<Vec<i64>>();
+
+    // Sort the autocomplete line scores and return the middle score
+    line_completion_scores.sort();
+    line_completion_scores[line_completion_scores.len() / 2]
+}
+
+fn check_lines(input_lines: &[Vec<char>]) -> Vec<Result<Vec<char>, char>> {
+    // Check the chunks of all input lines
+    input_lines
+        .iter()
+        .map(|line| check_line_chunks(line))
+        .collect()
+}
+
+fn generate_chunk_line(rng: &mut Rng, incomplete: bool) -> String {
+    // Random walk over opening and closing chars that never closes more chunks than are open
+    // At most 20 chunks are open at once, the autocomplete score of such a line fits into an i64
+    const OPENING: [char; 4] = ['(', '[', '{', '<'];
+    const CLOSING: [char; 4] = [')', ']', '}', '>'];
+    let mut line = String::new();
+    let mut open_chunks = Vec::new();
+    for _ in 0..rng.range(50..=110) {
+        if open_chunks.is_empty() || (open_chunks.len() < 20 && rng.chance(0.6)) {
+            let chunk = rng.below(4);
+            open_chunks.push(chunk);
+            line.push(OPENING[chunk]);
+        } else {
+            line.push(CLOSING[open_chunks.pop().unwrap()]);
+        }
+    }
+    if open_chunks.is_empty() {
+        let chunk = rng.below(4);
+        open_chunks.push(chunk);
+        line.push(OPENING[chunk]);
+    }
+    if !incomplete {
+        // Close the innermost chunk with a wrong char, the rest of the line is never checked
+        let wrong_chunk = (open_chunks.last().unwrap() + 1 + rng.below(3)) % 4;
+        line.push(CLOSING[wrong_chunk]);
+        for _ in 0..rng.below(20) {
+            line.push(*rng.choose(&[OPENING, CLOSING].concat()));
+        }
+    }
+    line
+}
+
+fn generate_navigation_subsystem(rng: &mut Rng, size: usize) -> String {
+    // The middle autocomplete score needs an odd number of incomplete lines
+    let mut incomplete_lines = (0..size).map(|i| i < (size / 2) | 1).collect::<Vec<bool>>();
+    rng.shuffle(&mut incomplete_lines);
+    incomplete_lines
+        .into_iter()
+        .map(|incomplete| format!("{}\n", generate_chunk_line(rng, incomplete)))
+        .collect()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u32 = 10;
+    const TITLE: &'static str = "Syntax Scoring";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_navigation_subsystem(rng, size)
+    }
+
+    fn part_one(input_lines: &Vec<Vec<char>>, _: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(calculate_syntax_error_score(&check_lines(
+            input_lines,
+        ))))
+    }
+
+    fn part_two(input_lines: &Vec<Vec<char>>, _: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(calculate_autocomplete_score(&check_lines(
+            input_lines,
+        ))))
+    }
+}
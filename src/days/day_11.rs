@@ -0,0 +1,229 @@
+use crate::cycle::CycleDetector;
+use crate::debug;
+use crate::frames::Frame;
+use crate::generate::{self, Rng};
+use crate::grid::Grid;
+use crate::netpbm::Image;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::InvalidInput;
+use crate::solution::{Answer, Part, Solution};
+
+// Example octopus energy levels from the puzzle description, used with --example
+const EXAMPLE: &str = "5483143223
+2745854711
+5264556173
+6141336146
+6357385478
+4167524645
+2176841721
+6882881134
+4846848554
+5283751526";
+
+fn parse_input_data(input: &str) -> Result<Grid<i32>, InvalidInput> {
+    // Create numerical octopus energy levels from input lines
+    Ok(Grid::parse_digits(Day11::DAY, input)?.map(|energy| *energy as i32))
+}
+
+fn simulate_step(octupus_energy_levels: Grid<i32>) -> (Grid<i32>, i32) {
+    //Simulate the octopus simulation for one step
+
+    // Increase all octopi by one
+    let mut increased_levels = octupus_energy_levels.map(|energy| energy + 1);
+
+    // Execute and count flashes as well as increase adjacent octopi energy levels
+    // Flashed octopi are marked with a -1
+    let mut flashed = true;
+    let mut flashes = 0;
+    while flashed {
+        flashed = false;
+        for position in increased_levels.positions() {
+            if increased_levels[position] > 9 {
+                flashed = true;
+                flashes += 1;
+                increased_levels[position] = -1;
+                let neighbors = increased_levels.neighbors_8(position).collect::<Vec<_>>();
+                for neighbor in neighbors {
+                    if increased_levels[neighbor] != -1 {
+                        increased_levels[neighbor] += 1;
+                    }
+                }
+            }
+        }
+    }
+
+    // Reset all energy levels after a flash
+    let levels_after_reset = increased_levels.map(|level| if *level == -1 { 0 } else { *level });
+    (levels_after_reset, flashes)
+}
+
+fn simulate_steps(octopus_starting_energy_levels: &Grid<i32>, steps: i64) -> (Grid<i32>, i32) {
+    // Simulate a number of steps and count all flashes
+    let mut octopus_flashes = 0;
+    let mut octopus_current_energy_levels = octopus_starting_energy_levels.clone();
+    for step in 1..=steps {
+        let step_results = simulate_step(octopus_current_energy_levels);
+        octopus_current_energy_levels = step_results.0;
+        octopus_flashes += step_results.1;
+        debug!("{} octopuses flash in step {}", step_results.1, step);
+    }
+    (octopus_current_energy_levels, octopus_flashes)
+}
+
+fn simulate_until_synchronized(
+    octopus_starting_energy_levels: &Grid<i32>,
+    mut visit: impl FnMut(i32, &Grid<i32>),
+) -> Result<(Grid<i32>, i32), i32> {
+    // Simulate until all octopuses flash in the same step
+    // Not every grid synchronizes, but the energy levels of every grid repeat at some point
+    // Repeating levels without a synchronized flash are returned as the length of the cycle
+    let mut octopus_current_energy_levels = octopus_starting_energy_levels.clone();
+    let mut cycle_detector = CycleDetector::new(octopus_starting_energy_levels);
+    let mut step = 0;
+    loop {
+        octopus_current_energy_levels = simulate_step(octopus_current_energy_levels).0;
+        step += 1;
+        visit(step, &octopus_current_energy_levels);
+        if octopus_current_energy_levels
+            .iter()
+            .all(|energy_level| *energy_level == 0)
+        {
+            return Ok((octopus_current_energy_levels, step));
+        }
+        if let Some(length) = cycle_detector.cycle_length(&octopus_current_energy_levels) {
+            return Err(length as i32);
+        }
+    }
+}
+
+fn energy_level_frames(
+    octopus_starting_energy_levels: &Grid<i32>,
+    part: Part,
+    steps: i64,
+    emit: &mut dyn FnMut(Frame),
+) {
+    // Part one stops after the steps, part two at the synchronized flash or the first repeated energy levels
+    let mut emit_step = |step: i64, energy_levels: &Grid<i32>| {
+        emit(Frame::image(
+            format!("step {}", step),
+            Image::Graymap(energy_levels.map(|energy_level| *energy_level as u16), 9),
+        ))
+    };
+    emit_step(0, octopus_starting_energy_levels);
+    match part {
+        Part::One => {
+            let mut octopus_current_energy_levels = octopus_starting_energy_levels.clone();
+            for step in 1..=steps {
+                octopus_current_energy_levels = simulate_step(octopus_current_energy_levels).0;
+                emit_step(step, &octopus_current_energy_levels);
+            }
+        }
+        Part::Two => {
+            let _ = simulate_until_synchronized(octopus_starting_energy_levels, |step, levels| {
+                emit_step(step as i64, levels)
+            });
+        }
+    }
+}
+
+fn generate_energy_levels(rng: &mut Rng, size: usize) -> String {
+    // Random energy levels on a square grid
+    // Random grids larger than the puzzle input rarely synchronize, which part two relies on
+    // Grids are retried with increasing minimum energy levels, as higher levels synchronize faster
+    // A grid of 9s flashes completely in the first step, so the search always ends
+    for minimum_energy_level in 0..=9 {
+        for _ in 0..3 {
+            let text = generate::grid(rng, size, size, |rng| {
+                generate::digit(rng, minimum_energy_level..=9)
+            });
+            let mut energy_levels = parse_input_data(&text).unwrap();
+            for _ in 0..1000 {
+                energy_levels = simulate_step(energy_levels).0;
+                if energy_levels.iter().all(|energy_level| *energy_level == 0) {
+                    return text;
+                }
+            }
+        }
+    }
+    unreachable!("A grid of 9s synchronizes in the first step")
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u32 = 11;
+    const TITLE: &'static str = "Dumbo Octopus";
+    const EXAMPLE: &'static str = EXAMPLE;
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "steps",
+        description: "Number of steps counting flashes, part 1 only",
+        defaults: [100, 100],
+        minimum: 0,
+        maximum: 100_000,
+    }];
+
+    type Input = Grid<i32>;
+
+    fn parse(input: &str) -> Result<Grid<i32>, InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_energy_levels(rng, size)
+    }
+
+    fn part_one(
+        octopus_starting_energy_levels: &Grid<i32>,
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let (_, octopus_flashes) =
+            simulate_steps(octopus_starting_energy_levels, parameters.get("steps"));
+        Ok(Answer::new(octopus_flashes))
+    }
+
+    fn part_two(
+        octopus_starting_energy_levels: &Grid<i32>,
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        match simulate_until_synchronized(octopus_starting_energy_levels, |_, _| {}) {
+            Ok((_, synchronized_flash_step)) => Ok(Answer::new(synchronized_flash_step)),
+            Err(cycle_length) => Err(format!(
+                "The octopuses never flash at once, their energy levels repeat every {} steps",
+                cycle_length
+            )),
+        }
+    }
+
+    fn image(
+        octopus_starting_energy_levels: &Grid<i32>,
+        part: Part,
+        parameters: &Parameters,
+    ) -> Option<Image> {
+        // Brightness shows the energy level, flashed octopuses are black
+        // Grids that never synchronize have no image for part two
+        let (octopus_energy_levels, _) = match part {
+            Part::One => simulate_steps(octopus_starting_energy_levels, parameters.get("steps")),
+            Part::Two => {
+                simulate_until_synchronized(octopus_starting_energy_levels, |_, _| {}).ok()?
+            }
+        };
+        Some(Image::Graymap(
+            octopus_energy_levels.map(|energy_level| *energy_level as u16),
+            9,
+        ))
+    }
+
+    fn frames(
+        octopus_starting_energy_levels: &Grid<i32>,
+        part: Part,
+        parameters: &Parameters,
+        emit: &mut dyn FnMut(Frame),
+    ) {
+        energy_level_frames(
+            octopus_starting_energy_levels,
+            part,
+            parameters.get("steps"),
+            emit,
+        );
+    }
+}
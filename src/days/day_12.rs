@@ -0,0 +1,208 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InvalidInput};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+// Example cave connections from the puzzle description, used with --example
+const EXAMPLE: &str = "start-A
+start-b
+A-c
+A-b
+b-d
+A-end
+b-end";
+
+enum CaveType {
+    Start,
+    Big,
+    Small,
+    End,
+}
+
+fn get_cave_type(id: &str) -> CaveType {
+    // Get the type of the cave based on the id
+    match id {
+        "start" => CaveType::Start,
+        "end" => CaveType::End,
+        s if s.chars().all(|c| c.is_uppercase()) => CaveType::Big,
+        _ => CaveType::Small,
+    }
+}
+
+fn create_cave_system(input_paths: &str) -> Result<HashMap<String, Vec<String>>, InvalidInput> {
+    // Create an adjacency list for each cave from the input data
+    let connections = parse::collect_all(parse::lines(Day12::DAY, input_paths).map(|line| {
+        let (start, end) = line.split_once(line.text, "-")?;
+        let caves = parse::collect_all([start, end].map(|cave| {
+            if cave.is_empty() || !cave.chars().all(|c| c.is_ascii_alphabetic()) {
+                Err(line.error(cave, "Invalid cave name"))
+            } else {
+                Ok(cave)
+            }
+        }))?;
+        // Big caves can be visited any number of times, two connected big caves allow endless paths
+        if caves
+            .iter()
+            .all(|cave| cave.chars().all(|c| c.is_ascii_uppercase()))
+        {
+            let message = "Expected at most one big cave in";
+            return Err(InvalidInput::from(line.error(line.text, message)));
+        }
+        Ok(caves)
+    }))?;
+
+    let mut paths: HashMap<String, Vec<String>> = HashMap::new();
+    for caves in connections {
+        let (start, end) = (caves[0], caves[1]);
+        if !paths.contains_key(start) {
+            paths.insert(String::from(start), Vec::new());
+        }
+        if !paths.contains_key(end) {
+            paths.insert(String::from(end), Vec::new());
+        }
+
+        paths.get_mut(start).unwrap().push(String::from(end));
+        paths.get_mut(end).unwrap().push(String::from(start));
+    }
+
+    // Paths are searched backwards from the end cave to the start cave
+    let end_of_input = parse::end_of_input(Day12::DAY, input_paths);
+    InvalidInput::from_violations(
+        ["start", "end"]
+            .into_iter()
+            .filter(|cave| !paths.contains_key(*cave))
+            .map(|cave| end_of_input.error("", &format!("Missing cave '{}'", cave)))
+            .collect(),
+    )?;
+
+    Ok(paths)
+}
+
+fn create_paths(
+    cave_system: &HashMap<String, Vec<String>>,
+    second_small_cave_visit: bool,
+) -> Vec<Vec<&str>> {
+    // Start at the end cave and search all valid paths to the start cave
+    let mut search_front: Vec<(Vec<&str>, bool)> = Vec::new();
+    for predecessor in cave_system.get("end").unwrap().iter().map(String::as_str) {
+        search_front.push((vec!["end", predecessor], false));
+    }
+    let mut valid_paths = Vec::new();
+    while !search_front.is_empty() {
+        let mut incomplete_paths: Vec<(Vec<&str>, bool)> = Vec::new();
+
+        for (path, second_visit_done) in search_front {
+            let current_cave = *path.last().unwrap();
+            if current_cave == "start" {
+                valid_paths.push(path.iter().cloned().rev().collect());
+            } else {
+                for predecessor in cave_system
+                    .get(current_cave)
+                    .unwrap()
+                    .iter()
+                    .map(String::as_str)
+                {
+                    let mut new_path = path.clone();
+                    new_path.push(predecessor);
+                    match get_cave_type(predecessor) {
+                        CaveType::Big | CaveType::Start => {
+                            incomplete_paths.push((new_path, second_visit_done));
+                        }
+                        CaveType::Small if !path.contains(&predecessor) => {
+                            incomplete_paths.push((new_path, second_visit_done));
+                        }
+                        CaveType::Small if second_small_cave_visit && !second_visit_done => {
+                            incomplete_paths.push((new_path, true))
+                        }
+                        _ => {}
+                    };
+                }
+            }
+        }
+
+        search_front = incomplete_paths;
+    }
+
+    valid_paths
+}
+
+fn cave_name(index: usize, big: bool) -> String {
+    // Two letter cave names, upper case for big caves
+    let first = if big { b'A' } else { b'a' };
+    format!(
+        "{}{}",
+        (first + (index / 26 % 26) as u8) as char,
+        (first + (index % 26) as u8) as char
+    )
+}
+
+fn generate_cave_connections(rng: &mut Rng, size: usize) -> String {
+    // The size is the number of small caves, a third as many big caves are added
+    // Big caves are never connected to each other, that would allow endless paths
+    let mut caves = (0..size)
+        .map(|index| cave_name(index, false))
+        .chain((0..size.div_ceil(3)).map(|index| cave_name(index, true)))
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut caves);
+    caves.insert(0, String::from("start"));
+    caves.push(String::from("end"));
+    let is_big = |cave: usize| matches!(get_cave_type(&caves[cave]), CaveType::Big);
+
+    // Connecting each cave to one of the caves before it makes all caves reachable from the start
+    let mut connections = Vec::new();
+    for cave in 1..caves.len() {
+        let other_cave = match rng.below(cave) {
+            other_cave if is_big(cave) && is_big(other_cave) => 0,
+            other_cave => other_cave,
+        };
+        connections.push((other_cave, cave));
+    }
+    // Additional connections create alternative paths
+    for _ in 0..size / 2 {
+        let (cave_1, cave_2) = (rng.below(caves.len()), rng.below(caves.len()));
+        let connection = (usize::min(cave_1, cave_2), usize::max(cave_1, cave_2));
+        if cave_1 != cave_2
+            && !(is_big(cave_1) && is_big(cave_2))
+            && !connections.contains(&connection)
+        {
+            connections.push(connection);
+        }
+    }
+    connections
+        .into_iter()
+        .map(|(cave_1, cave_2)| format!("{}-{}\n", caves[cave_1], caves[cave_2]))
+        .collect()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u32 = 12;
+    const TITLE: &'static str = "Passage Pathing";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = HashMap<String, Vec<String>>;
+
+    fn parse(input: &str) -> Result<HashMap<String, Vec<String>>, InvalidInput> {
+        create_cave_system(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_cave_connections(rng, size)
+    }
+
+    fn part_one(
+        cave_system: &HashMap<String, Vec<String>>,
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        Ok(Answer::new(create_paths(cave_system, false).len()))
+    }
+
+    fn part_two(
+        cave_system: &HashMap<String, Vec<String>>,
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        Ok(Answer::new(create_paths(cave_system, true).len()))
+    }
+}
@@ -0,0 +1,297 @@
+use crate::frames::Frame;
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::netpbm::Image;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Part, Solution};
+use crate::{info, trace};
+use std::cmp::max;
+use std::collections::HashSet;
+
+// Example transparent paper dots and folds from the puzzle description, used with --example
+const EXAMPLE: &str = "6,10
+0,14
+9,10
+0,3
+10,4
+4,11
+6,0
+6,12
+4,1
+0,13
+10,12
+3,4
+3,0
+8,4
+1,10
+2,14
+8,10
+9,0
+
+fold along y=7
+fold along x=5";
+
+// Set of the dot coordinates on the transparent paper
+pub type Dots = HashSet<(usize, usize)>;
+
+fn parse_dot(line: InputLine) -> Result<(usize, usize), InvalidInput> {
+    let (x, y) = line.split_once(line.text, ",")?;
+    parse::combine(
+        line.parse(x, "x coordinate").map_err(InvalidInput::from),
+        line.parse(y, "y coordinate").map_err(InvalidInput::from),
+    )
+}
+
+fn parse_fold_instruction(line: InputLine) -> Result<(char, usize), ParseError> {
+    let (axis, value) = match line.text.strip_prefix("fold along ") {
+        Some(instruction) => line.split_once(instruction, "=")?,
+        None => return Err(line.error(line.text, "Expected 'fold along' instead of")),
+    };
+    let axis = match axis {
+        "x" => 'x',
+        "y" => 'y',
+        _ => return Err(line.error(axis, "Fold axis has to be x or y, found")),
+    };
+    Ok((axis, line.parse(value, "fold line")?))
+}
+
+fn parse_input_data(input_data: &str) -> Result<(Dots, Vec<(char, usize)>), InvalidInput> {
+    // Split the input lines into point coordinates and fold instructions
+    // The sections are separated by an empty line, at least one fold is needed for part one
+    let lines = parse::lines(Day13::DAY, input_data).collect::<Vec<InputLine>>();
+    let separator = lines.iter().position(|line| line.text.is_empty());
+    let (dot_lines, instruction_lines) = match separator {
+        Some(index) => (&lines[..index], &lines[index + 1..]),
+        None => (&lines[..], &lines[lines.len()..]),
+    };
+    let dots = parse::collect_all(dot_lines.iter().cloned().map(parse_dot));
+    let fold_instructions = match instruction_lines {
+        [] => Err(InvalidInput::from(
+            parse::end_of_input(Day13::DAY, input_data).error("", "Missing fold instructions"),
+        )),
+        _ => parse::collect_all(
+            instruction_lines
+                .iter()
+                .cloned()
+                .map(parse_fold_instruction),
+        ),
+    };
+    let (dots, fold_instructions) = parse::combine(dots, fold_instructions)?;
+
+    // A fold mirrors the dots behind the fold line, a dot more than twice the fold line away from the edge
+    // would end up beyond the edge of the paper
+    let violations = dot_lines
+        .iter()
+        .zip(dots.iter())
+        .filter_map(|(line, (x, y))| {
+            let (mut x, mut y) = (*x, *y);
+            for (axis, fold_line) in fold_instructions.iter() {
+                let coordinate = if *axis == 'x' { &mut x } else { &mut y };
+                if *coordinate > *fold_line {
+                    if *coordinate - fold_line > *fold_line {
+                        let (x_text, y_text) = line.text.split_once(',').unwrap();
+                        return Some(line.error(
+                            if *axis == 'x' { x_text } else { y_text },
+                            &format!(
+                                "Fold along {}={} mirrors the dot off the paper, coordinate",
+                                axis, fold_line
+                            ),
+                        ));
+                    }
+                    *coordinate = fold_line - (*coordinate - fold_line);
+                }
+            }
+            None
+        })
+        .collect();
+    InvalidInput::from_violations(violations)?;
+
+    Ok((dots.into_iter().collect(), fold_instructions))
+}
+
+fn fold_paper(points: &Dots, fold_direction: char, fold_line: usize) -> Dots {
+    // Reduce the set of points by executing a fold instruction
+
+    // Partition the points by the fold line
+    let (first_half, second_half): (Dots, Dots) = points.iter().partition(|(x, y)| {
+        if fold_direction == 'x' {
+            *x < fold_line
+        } else {
+            *y < fold_line
+        }
+    });
+
+    // Project the points from the second half to the first half
+    // and create the combined point hash set
+    let mut remaining_points = first_half;
+    for (x, y) in second_half {
+        let point_after_fold = if fold_direction == 'x' {
+            (fold_line - (x - fold_line), y)
+        } else {
+            (x, fold_line - (y - fold_line))
+        };
+        remaining_points.insert(point_after_fold);
+    }
+
+    remaining_points
+}
+
+fn paper_grid(points: &Dots) -> Grid<bool> {
+    // Grid of the paper up to the outermost dots, dots are set cells
+    let (max_x, max_y) = points
+        .iter()
+        .cloned()
+        .fold((0, 0), |(m_x, m_y), (x, y)| (max(m_x, x), max(m_y, y)));
+    Grid::from_fn(max_x + 1, max_y + 1, |position| points.contains(&position))
+}
+
+fn render_points(points: &Dots) -> String {
+    // Render the points on the paper as lines of '#' and '.' chars
+    paper_grid(points)
+        .rows()
+        .map(|line| {
+            line.iter()
+                .map(|dot| if *dot { '#' } else { '.' })
+                .collect::<String>()
+        })
+        .collect::<Vec<String>>()
+        .join("\n")
+}
+
+fn log_fold(fold_direction: char, fold_line: usize, points: &Dots) {
+    info!(
+        "Fold along {}={} leaves {} dots",
+        fold_direction,
+        fold_line,
+        points.len()
+    );
+    trace!("Paper after the fold:\n{}", render_points(points));
+}
+
+fn generate_paper(rng: &mut Rng, size: usize) -> String {
+    // Like in the puzzle, 12 folds reduce a 1311x895 sheet to a 40x6 code area
+    // Each fold line is in the middle of the remaining sheet, the largest folds come first
+    let fold_lines = |code_area_size: usize, folds: usize| {
+        let mut lines = std::iter::successors(Some(code_area_size), |line| Some(2 * line + 1))
+            .take(folds)
+            .collect::<Vec<usize>>();
+        lines.reverse();
+        lines.into_iter()
+    };
+    let (mut x_folds, mut y_folds) = (fold_lines(40, 5), fold_lines(6, 7));
+    let mut fold_instructions = Vec::new();
+    while x_folds.len() + y_folds.len() > 0 {
+        fold_instructions.extend(x_folds.next().map(|line| ('x', line)));
+        fold_instructions.extend(y_folds.next().map(|line| ('y', line)));
+    }
+
+    // Dots start in the code area and are mirrored back at random fold lines
+    // Dots therefore never lie on a fold line and stay on the sheet
+    let mut dots = (0..size)
+        .map(|_| {
+            let (mut x, mut y) = (rng.below(40), rng.below(6));
+            for (axis, line) in fold_instructions.iter().rev() {
+                if rng.chance(0.5) {
+                    match axis {
+                        'x' => x = 2 * line - x,
+                        _ => y = 2 * line - y,
+                    }
+                }
+            }
+            (x, y)
+        })
+        .collect::<Vec<(usize, usize)>>();
+    dots.sort();
+    dots.dedup();
+    rng.shuffle(&mut dots);
+
+    let mut text = dots
+        .into_iter()
+        .map(|(x, y)| format!("{},{}\n", x, y))
+        .collect::<String>();
+    text.push('\n');
+    for (axis, line) in fold_instructions {
+        text.push_str(&format!("fold along {}={}\n", axis, line));
+    }
+    text
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u32 = 13;
+    const TITLE: &'static str = "Transparent Origami";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = (Dots, Vec<(char, usize)>);
+
+    fn parse(input: &str) -> Result<(Dots, Vec<(char, usize)>), InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_paper(rng, size)
+    }
+
+    fn part_one(
+        (points, fold_instructions): &(Dots, Vec<(char, usize)>),
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        let (first_fold_direction, first_fold_line) = fold_instructions[0];
+        let points = fold_paper(points, first_fold_direction, first_fold_line);
+        log_fold(first_fold_direction, first_fold_line, &points);
+        Ok(Answer::new(points.len()))
+    }
+
+    fn part_two(
+        (points, fold_instructions): &(Dots, Vec<(char, usize)>),
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        let mut points = points.clone();
+        for (fold_direction, fold_line) in fold_instructions.iter().cloned() {
+            points = fold_paper(&points, fold_direction, fold_line);
+            log_fold(fold_direction, fold_line, &points);
+        }
+        Ok(Answer::new(render_points(&points)))
+    }
+
+    fn image(
+        (points, fold_instructions): &(Dots, Vec<(char, usize)>),
+        part: Part,
+        _: &Parameters,
+    ) -> Option<Image> {
+        // Part one stops after the first fold
+        let folds = match part {
+            Part::One => 1,
+            Part::Two => fold_instructions.len(),
+        };
+        let mut points = points.clone();
+        for (fold_direction, fold_line) in fold_instructions.iter().take(folds).cloned() {
+            points = fold_paper(&points, fold_direction, fold_line);
+        }
+        Some(Image::Bitmap(paper_grid(&points)))
+    }
+
+    fn frames(
+        (points, fold_instructions): &(Dots, Vec<(char, usize)>),
+        part: Part,
+        _: &Parameters,
+        emit: &mut dyn FnMut(Frame),
+    ) {
+        // The unfolded paper followed by the paper after each fold
+        let folds = match part {
+            Part::One => 1,
+            Part::Two => fold_instructions.len(),
+        };
+        let mut points = points.clone();
+        emit(Frame::image("unfolded", Image::Bitmap(paper_grid(&points))));
+        for (fold_direction, fold_line) in fold_instructions.iter().take(folds).cloned() {
+            points = fold_paper(&points, fold_direction, fold_line);
+            emit(Frame::image(
+                format!("fold along {}={}", fold_direction, fold_line),
+                Image::Bitmap(paper_grid(&points)),
+            ));
+        }
+    }
+}
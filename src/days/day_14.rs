@@ -0,0 +1,232 @@
+use crate::debug;
+use crate::generate::Rng;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+
+// Example polymer template and insertion rules from the puzzle description, used with --example
+const EXAMPLE: &str = "NNCB
+
+CH -> B
+HH -> N
+CB -> H
+NH -> C
+HB -> C
+HC -> B
+HN -> C
+NN -> C
+BH -> H
+NC -> B
+NB -> B
+BN -> B
+BB -> N
+BC -> B
+CC -> N
+CN -> C";
+
+// Mapping from a pair to the two pairs and the char created by its insertion rule
+pub type ReplacementRules = HashMap<String, (String, String, char)>;
+
+fn check_elements(line: &InputLine, text: &str) -> Vec<ParseError> {
+    // Elements are single uppercase letters, the pairs of the polymer are sliced by bytes
+    text.char_indices()
+        .filter(|(_, c)| !c.is_ascii_uppercase())
+        .map(|(index, c)| line.error(&text[index..index + c.len_utf8()], "Invalid element"))
+        .collect()
+}
+
+fn parse_replacement_rule(
+    line: InputLine,
+) -> Result<(String, (String, String, char)), InvalidInput> {
+    let (pattern, replacement) = line.split_once(line.text, " -> ")?;
+    let mut violations = Vec::new();
+    if pattern.chars().count() != 2 {
+        violations.push(line.error(pattern, "Expected a pair of elements instead of"));
+    }
+    if replacement.chars().count() != 1 {
+        violations.push(line.error(replacement, "Expected a single element instead of"));
+    }
+    violations.extend(check_elements(&line, pattern));
+    violations.extend(check_elements(&line, replacement));
+    InvalidInput::from_violations(violations)?;
+    Ok((
+        String::from(pattern),
+        (
+            format!("{}{}", pattern.chars().next().unwrap(), replacement),
+            format!("{}{}", replacement, pattern.chars().last().unwrap()),
+            replacement.chars().next().unwrap(),
+        ),
+    ))
+}
+
+fn parse_input_data(input_data: &str) -> Result<(String, ReplacementRules), InvalidInput> {
+    // Split into input text and replacement rules
+    // Replacement rules are mappings from pairs to the two new pairs and the new char
+    let mut lines = parse::lines(Day14::DAY, input_data);
+    let start_text = match lines.next() {
+        Some(line) if !line.text.is_empty() => {
+            InvalidInput::from_violations(check_elements(&line, line.text)).map(|_| line.text)
+        }
+        Some(line) => Err(line.end_error("Missing polymer template").into()),
+        None => Err(parse::end_of_input(Day14::DAY, input_data)
+            .error("", "Missing polymer template")
+            .into()),
+    };
+    let separator = match lines.next().filter(|line| !line.text.is_empty()) {
+        Some(line) => Err(line
+            .error(line.text, "Expected an empty line instead of")
+            .into()),
+        None => Ok(()),
+    };
+    let replacements = parse::collect_all(lines.map(parse_replacement_rule));
+    let ((start_text, ()), replacements) =
+        parse::combine(parse::combine(start_text, separator), replacements)?;
+
+    Ok((String::from(start_text), replacements.into_iter().collect()))
+}
+
+pub fn initial_chars_count(text: &str) -> HashMap<char, i64> {
+    // Count chars in the input text
+    let mut counter = HashMap::new();
+    for c in text.chars() {
+        *counter.entry(c).or_default() += 1;
+    }
+    counter
+}
+
+pub fn initial_pairs_count(text: &str) -> HashMap<String, i64> {
+    // Count pairs in the input text
+    let mut pair_counter = HashMap::new();
+    for i in 0..(text.len() - 1) {
+        let pair = String::from(&text[i..i + 2]);
+        *pair_counter.entry(pair).or_default() += 1;
+    }
+    pair_counter
+}
+
+pub fn execute_replacement_step(
+    pair_counter: HashMap<String, i64>,
+    char_counter: HashMap<char, i64>,
+    replacement_rules: &ReplacementRules,
+) -> (HashMap<String, i64>, HashMap<char, i64>) {
+    // Get current pair counts and char counts
+    // Execute all applicable replacements and update counters
+    // For all pairs without a replacement rule, keep the current count
+    let mut pair_counter_with_replacements = HashMap::new();
+    let mut char_counter_with_replacements = char_counter;
+
+    for (pair, count) in pair_counter {
+        if let Some((pair_1, pair_2, new_char)) = replacement_rules.get(&pair) {
+            *pair_counter_with_replacements
+                .entry(pair_1.clone())
+                .or_default() += count;
+            *pair_counter_with_replacements
+                .entry(pair_2.clone())
+                .or_default() += count;
+            *char_counter_with_replacements.entry(*new_char).or_default() += count;
+        } else {
+            *pair_counter_with_replacements
+                .entry(pair.clone())
+                .or_default() += count;
+        }
+    }
+
+    (
+        pair_counter_with_replacements,
+        char_counter_with_replacements,
+    )
+}
+
+fn execute_replacements(
+    text: &str,
+    replacement_steps: i64,
+    replacements: &ReplacementRules,
+) -> HashMap<char, i64> {
+    // Iteratively execute replacements and update counters
+    let mut pair_counter = initial_pairs_count(text);
+    let mut char_counter = initial_chars_count(text);
+    for step in 1..=replacement_steps {
+        let counters = execute_replacement_step(pair_counter, char_counter, replacements);
+        pair_counter = counters.0;
+        char_counter = counters.1;
+        debug!(
+            "Polymer has {} elements after step {}",
+            char_counter.values().sum::<i64>(),
+            step
+        );
+    }
+    char_counter
+}
+
+fn get_min_and_max(counter: HashMap<char, i64>) -> (i64, i64) {
+    // Get min and max count of a hashmap
+    (
+        *counter.values().min().unwrap(),
+        *counter.values().max().unwrap(),
+    )
+}
+
+fn generate_polymer_instructions(rng: &mut Rng, size: usize) -> String {
+    // Template of the given length and an insertion rule for every pair of the 10 elements
+    let elements = "BCFHKNOPSV".chars().collect::<Vec<char>>();
+    let mut text = (0..size)
+        .map(|_| *rng.choose(&elements))
+        .collect::<String>();
+    text.push_str("\n\n");
+    for first in elements.iter() {
+        for second in elements.iter() {
+            text.push_str(&format!(
+                "{}{} -> {}\n",
+                first,
+                second,
+                rng.choose(&elements)
+            ));
+        }
+    }
+    text
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u32 = 14;
+    const TITLE: &'static str = "Extended Polymerization";
+    const EXAMPLE: &'static str = EXAMPLE;
+    // The polymer doubles in each step, after 48 steps templates of thousands of elements still fit into 64 bits
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "steps",
+        description: "Number of pair insertion steps",
+        defaults: [10, 40],
+        minimum: 0,
+        maximum: 48,
+    }];
+
+    type Input = (String, ReplacementRules);
+
+    fn parse(input: &str) -> Result<(String, ReplacementRules), InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_polymer_instructions(rng, size)
+    }
+
+    fn part_one(
+        (start_text, replacements): &(String, ReplacementRules),
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let char_counter = execute_replacements(start_text, parameters.get("steps"), replacements);
+        let (min_char_count, max_char_count) = get_min_and_max(char_counter);
+        Ok(Answer::new(max_char_count - min_char_count))
+    }
+
+    fn part_two(
+        (start_text, replacements): &(String, ReplacementRules),
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let char_counter = execute_replacements(start_text, parameters.get("steps"), replacements);
+        let (min_char_count, max_char_count) = get_min_and_max(char_counter);
+        Ok(Answer::new(max_char_count - min_char_count))
+    }
+}
@@ -0,0 +1,147 @@
+use crate::generate::{self, Rng};
+use crate::grid::Grid;
+use crate::netpbm::Image;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::InvalidInput;
+use crate::solution::{Answer, Part, Solution};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+// Example risk level map from the puzzle description, used with --example
+const EXAMPLE: &str = "1163751742
+1381373672
+2136511328
+3694931569
+7463417111
+1319128137
+1359912421
+3125421639
+1293138521
+2311944581";
+
+fn parse_risk_level_map(input_data: &str) -> Result<Grid<u32>, InvalidInput> {
+    // Get a numerical risk level map from the input lines
+    Grid::parse_digits(Day15::DAY, input_data)
+}
+
+fn extend_map(map: &Grid<u32>, repetitions: usize) -> Grid<u32> {
+    // Repeat the map in both directions, each repetition increases the risk levels by one
+    let (original_x_size, original_y_size) = (map.width(), map.height());
+    Grid::from_fn(
+        original_x_size * repetitions,
+        original_y_size * repetitions,
+        |(x, y)| {
+            (map[(x % original_x_size, y % original_y_size)]
+                + y as u32 / original_y_size as u32
+                + x as u32 / original_x_size as u32
+                - 1)
+                % 9
+                + 1
+        },
+    )
+}
+
+// Struct for elements of the search priority queue of Dijkstra algorithm
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct QueueElement {
+    cost: u32,
+    coordinates: (usize, usize),
+}
+
+impl Ord for QueueElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.cost.cmp(&self.cost)
+    }
+}
+
+impl PartialOrd for QueueElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+pub fn find_min_path_to_target(map: &Grid<u32>) -> u32 {
+    // Dijkstra algorithm to find shortest paths from the start field (0, 0)
+    let mut costs = map.map(|_| None);
+    let mut open_fields = map.map(|_| true);
+    let mut queue = BinaryHeap::new();
+    costs[(0, 0)] = Some(0);
+    open_fields[(0, 0)] = false;
+    queue.push(QueueElement {
+        cost: 0,
+        coordinates: (0, 0),
+    });
+
+    while let Some(QueueElement { cost, coordinates }) = queue.pop() {
+        for neighbor in map.neighbors_4(coordinates) {
+            if open_fields[neighbor] {
+                open_fields[neighbor] = false;
+                let neighbor_cost = cost + map[neighbor];
+                let update = if let Some(current_cost) = costs[neighbor] {
+                    current_cost > cost
+                } else {
+                    true
+                };
+                if update {
+                    queue.push(QueueElement {
+                        cost: neighbor_cost,
+                        coordinates: neighbor,
+                    });
+                    costs[neighbor] = Some(neighbor_cost);
+                }
+            }
+        }
+    }
+
+    costs[(map.width() - 1, map.height() - 1)].unwrap()
+}
+
+fn generate_risk_level_map(rng: &mut Rng, size: usize) -> String {
+    // Random risk levels between 1 and 9 on a square map
+    generate::grid(rng, size, size, |rng| generate::digit(rng, 1..=9))
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u32 = 15;
+    const TITLE: &'static str = "Chiton";
+    const EXAMPLE: &'static str = EXAMPLE;
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "tiles",
+        description: "Number of map repetitions in both directions",
+        defaults: [1, 5],
+        minimum: 1,
+        maximum: 50,
+    }];
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>, InvalidInput> {
+        parse_risk_level_map(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_risk_level_map(rng, size)
+    }
+
+    fn part_one(risk_level_map: &Grid<u32>, parameters: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(find_min_path_to_target(&extend_map(
+            risk_level_map,
+            parameters.get("tiles") as usize,
+        ))))
+    }
+
+    fn part_two(risk_level_map: &Grid<u32>, parameters: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(find_min_path_to_target(&extend_map(
+            risk_level_map,
+            parameters.get("tiles") as usize,
+        ))))
+    }
+
+    fn image(risk_level_map: &Grid<u32>, _: Part, parameters: &Parameters) -> Option<Image> {
+        // Brightness shows the risk level of the map the part searches
+        let map = extend_map(risk_level_map, parameters.get("tiles") as usize);
+        Some(Image::Graymap(map.map(|risk_level| *risk_level as u16), 9))
+    }
+}
@@ -0,0 +1,515 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Solution};
+
+// Example transmission from the puzzle description, used with --example
+const EXAMPLE: &str = "9C0141080250320F1802104A08";
+
+pub fn hex_to_binary_string(line: &InputLine) -> Result<String, InvalidInput> {
+    // Transform each hexadecimal to a four bit binary and concatenate all binaries
+    let digits = parse::collect_all(
+        line.text
+            .chars()
+            .enumerate()
+            .map(|(index, c)| line.digit(index, c, 16)),
+    )?;
+    Ok(digits
+        .iter()
+        .map(|digit| format!("{:04b}", digit))
+        .collect())
+}
+
+// Malformed packet inside the binary transmission
+// The position is given as number of bits left in the transmission, as packets only see their remaining bits
+#[derive(Debug)]
+pub struct PacketError {
+    remaining_bits: usize,
+    message: &'static str,
+}
+
+pub type PacketResult<'a, T> = Result<(Option<&'a str>, T), PacketError>;
+
+fn packet_error<T>(binary_string: &str, message: &'static str) -> Result<T, PacketError> {
+    Err(PacketError {
+        remaining_bits: binary_string.len(),
+        message,
+    })
+}
+
+fn binary_string_to_number(binary_string: &str) -> i64 {
+    // Parse an integer number from a binary string
+    i64::from_str_radix(binary_string, 2).unwrap()
+}
+
+#[derive(Debug)]
+pub enum PacketType {
+    LiteralValueType,
+    OperatorType(OperatorType),
+}
+
+#[derive(Debug)]
+pub struct Header {
+    version: i64,
+    packet_type: PacketType,
+}
+
+impl Header {
+    fn from_string(binary_string: &str) -> Result<Header, PacketError> {
+        // Create a header from the first 6 bits of a binary input string, i.e. version and packet type
+        let packet_type = match binary_string_to_number(&binary_string[3..6]) {
+            4 => PacketType::LiteralValueType,
+            type_id => match OperatorType::from_type_id(type_id) {
+                Some(operation) => PacketType::OperatorType(operation),
+                None => return packet_error(binary_string, "Invalid packet type id"),
+            },
+        };
+        Ok(Header {
+            version: binary_string_to_number(&binary_string[0..3]),
+            packet_type,
+        })
+    }
+}
+
+trait VersionSum {
+    fn calculate_version_sum(&self) -> i64;
+}
+
+trait FromBinaryString: Sized {
+    fn from_string(header: Header, binary_string: &str) -> PacketResult<'_, Self>;
+}
+
+#[derive(Debug)]
+pub struct LiteralValuePacket {
+    header: Header,
+    value: i64,
+}
+
+impl VersionSum for LiteralValuePacket {
+    fn calculate_version_sum(&self) -> i64 {
+        // Version sum of a literal value packet is the version value from the header
+        self.header.version
+    }
+}
+
+impl FromBinaryString for LiteralValuePacket {
+    fn from_string(header: Header, binary_string: &str) -> PacketResult<'_, LiteralValuePacket> {
+        // Create a Literal value packet from a binary string
+        // The value is built group by group, leading zero groups do not count towards its 63 bits
+        let mut value: i64 = 0;
+        let mut remaining_binary_string_index = None;
+        // Iterate over the binary input in chunks of 5 bits until a chunk starts with 0 bit
+        for (n, packet) in binary_string
+            .chars()
+            .collect::<Vec<char>>()
+            .chunks(5)
+            .enumerate()
+        {
+            if packet.len() < 5 {
+                return packet_error(&binary_string[n * 5..], "Truncated literal value");
+            }
+            let last_packet = packet[0] == '0';
+            let group = binary_string_to_number(&packet[1..5].iter().collect::<String>());
+            value = match value.checked_mul(16) {
+                Some(value) => value + group,
+                None => return packet_error(binary_string, "Literal value exceeds 63 bits"),
+            };
+            if last_packet {
+                remaining_binary_string_index = Some((n + 1) * 5);
+                break;
+            }
+        }
+        let remaining_binary_string_index = match remaining_binary_string_index {
+            Some(index) => index,
+            None => return packet_error("", "Literal value without last group"),
+        };
+        let packet = LiteralValuePacket { header, value };
+        // Return the created literal value packet and the remaining binary string if there are bits left
+        if remaining_binary_string_index < binary_string.len() {
+            Ok((
+                Some(&binary_string[remaining_binary_string_index..]),
+                packet,
+            ))
+        } else {
+            Ok((None, packet))
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum OperatorType {
+    Sum,
+    Product,
+    Minimum,
+    Maximum,
+    GreaterThan,
+    LessThan,
+    EqualTo,
+}
+
+impl OperatorType {
+    fn from_type_id(type_id: i64) -> Option<OperatorType> {
+        // Map a header type id to a operator evaluation method, 4 is the type id of literal values
+        match type_id {
+            0 => Some(OperatorType::Sum),
+            1 => Some(OperatorType::Product),
+            2 => Some(OperatorType::Minimum),
+            3 => Some(OperatorType::Maximum),
+            5 => Some(OperatorType::GreaterThan),
+            6 => Some(OperatorType::LessThan),
+            7 => Some(OperatorType::EqualTo),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct OperatorPacket {
+    header: Header,
+    operation: OperatorType,
+    sub_packets: Vec<Packet>,
+}
+
+impl OperatorPacket {
+    fn evaluate_operation(&self) -> Option<i64> {
+        // Evaluates the values of an operator packet
+        // Which is the operation evaluation method executed on all sub-packets
+        // None if a sum or product exceeds the range of i64
+        let values = self
+            .sub_packets
+            .iter()
+            .map(|sub_packet| sub_packet.evaluate_expression())
+            .collect::<Option<Vec<i64>>>()?;
+        Some(match self.operation {
+            OperatorType::Sum => values
+                .iter()
+                .try_fold(0, |sum: i64, value| sum.checked_add(*value))?,
+            OperatorType::Product => values
+                .iter()
+                .try_fold(1, |product: i64, value| product.checked_mul(*value))?,
+            OperatorType::Minimum => *values.iter().min().unwrap(),
+            OperatorType::Maximum => *values.iter().max().unwrap(),
+            OperatorType::GreaterThan => {
+                let first_sub_packet_value = values[0];
+                let second_sub_packet_value = values[1];
+                if first_sub_packet_value > second_sub_packet_value {
+                    1
+                } else {
+                    0
+                }
+            }
+            OperatorType::LessThan => {
+                let first_sub_packet_value = values[0];
+                let second_sub_packet_value = values[1];
+                if first_sub_packet_value < second_sub_packet_value {
+                    1
+                } else {
+                    0
+                }
+            }
+            OperatorType::EqualTo => {
+                let first_sub_packet_value = values[0];
+                let second_sub_packet_value = values[1];
+                if first_sub_packet_value == second_sub_packet_value {
+                    1
+                } else {
+                    0
+                }
+            }
+        })
+    }
+}
+
+impl VersionSum for OperatorPacket {
+    fn calculate_version_sum(&self) -> i64 {
+        // Version sum of an operator packet is the version from the header plus the version sums of all sub-packets
+        let mut version_sum = self.header.version;
+        for sub_packet in &self.sub_packets {
+            version_sum += match sub_packet {
+                Packet::LiteralValue(literal_value_packet) => {
+                    literal_value_packet.calculate_version_sum()
+                }
+                Packet::Operator(operator_packet) => operator_packet.calculate_version_sum(),
+            };
+        }
+        version_sum
+    }
+}
+
+impl FromBinaryString for OperatorPacket {
+    fn from_string(header: Header, binary_string: &str) -> PacketResult<'_, OperatorPacket> {
+        // Create an operator packet from a binary input string
+        // Start with determining the operation evaluation method based on the header type id
+        let operation = match header.packet_type {
+            PacketType::OperatorType(operation) => operation,
+            PacketType::LiteralValueType => {
+                return packet_error(binary_string, "Expected an operator packet")
+            }
+        };
+
+        let (remaining_string_after_sub_packets, sub_packets) = if binary_string.starts_with('0') {
+            // Length of the sub-packet string is given
+            if binary_string.len() < 16 {
+                return packet_error(binary_string, "Truncated sub-packets length");
+            }
+            let sub_packets_end_index =
+                binary_string_to_number(&binary_string[1..16]) as usize + 16;
+            if sub_packets_end_index > binary_string.len() {
+                return packet_error(&binary_string[16..], "Sub-packets exceed the transmission");
+            }
+
+            // Get remaining binary input after the sub-packets end if more bits exist
+            let remaining_string_after_sub_packets = if binary_string.len() > sub_packets_end_index
+            {
+                Some(&binary_string[sub_packets_end_index..])
+            } else {
+                None
+            };
+
+            // Create all sub-packets by creating as much sub-packets as possible from the binary string with the given length
+            let mut sub_packets = Vec::new();
+            let mut remaining_sub_packets_binary_string =
+                Some(&binary_string[16..sub_packets_end_index]);
+            while let Some(remaining) = remaining_sub_packets_binary_string {
+                let (remaining_after_sub_packet, sub_packet) = parse_packet(remaining)?;
+                remaining_sub_packets_binary_string = remaining_after_sub_packet;
+                if let Some(sub_packet) = sub_packet {
+                    sub_packets.push(sub_packet);
+                }
+            }
+
+            (remaining_string_after_sub_packets, sub_packets)
+        } else {
+            // Number of sub packets is given
+            if binary_string.len() < 12 {
+                return packet_error(binary_string, "Truncated number of sub-packets");
+            }
+            let number_of_sub_packets = binary_string_to_number(&binary_string[1..12]);
+
+            // Create the requested number of sub-packets
+            let mut sub_packets = Vec::new();
+            let mut remaining_string = Some(&binary_string[12..]);
+            for _ in 0..number_of_sub_packets {
+                let remaining = remaining_string.unwrap_or("");
+                match parse_packet(remaining)? {
+                    // Update the remaining binary input after this sub-packets was created if more bits exist
+                    (remaining_after_sub_packet, Some(sub_packet)) => {
+                        remaining_string = remaining_after_sub_packet;
+                        sub_packets.push(sub_packet);
+                    }
+                    (_, None) => return packet_error(remaining, "Missing sub-packet"),
+                }
+            }
+
+            (remaining_string, sub_packets)
+        };
+
+        // The evaluation needs at least one value and comparisons exactly two values
+        match (&operation, sub_packets.len()) {
+            (_, 0) => return packet_error(binary_string, "Operator packet without sub-packets"),
+            (OperatorType::GreaterThan | OperatorType::LessThan | OperatorType::EqualTo, n)
+                if n != 2 =>
+            {
+                return packet_error(binary_string, "Comparison packet needs two sub-packets")
+            }
+            _ => {}
+        }
+
+        // Return the remining input bits if some exist
+        // and also create and return the Operator packet with the found sub-packets
+        Ok((
+            remaining_string_after_sub_packets,
+            OperatorPacket {
+                header,
+                operation,
+                sub_packets,
+            },
+        ))
+    }
+}
+
+// Wrapper code for handling both packet types interchangable
+#[derive(Debug)]
+pub enum Packet {
+    LiteralValue(LiteralValuePacket),
+    Operator(OperatorPacket),
+}
+
+impl Packet {
+    fn get_version_number(&self) -> i64 {
+        match self {
+            Packet::LiteralValue(p) => p.calculate_version_sum(),
+            Packet::Operator(p) => p.calculate_version_sum(),
+        }
+    }
+
+    fn evaluate_expression(&self) -> Option<i64> {
+        match self {
+            Packet::LiteralValue(p) => Some(p.value),
+            Packet::Operator(p) => p.evaluate_operation(),
+        }
+    }
+}
+
+pub fn parse_packet(binary_string: &str) -> PacketResult<'_, Option<Packet>> {
+    // Parse a packet from a binary input string based on the information of the header data at the start
+    // Less than 11 bits can not contain a packet and are treated as padding
+    if binary_string.len() >= 11 {
+        let header = Header::from_string(binary_string)?;
+        let packet_binary_string = &binary_string[6..];
+        match header.packet_type {
+            PacketType::LiteralValueType => {
+                let (remaining_string, packet) =
+                    LiteralValuePacket::from_string(header, packet_binary_string)?;
+                return Ok((remaining_string, Some(Packet::LiteralValue(packet))));
+            }
+            PacketType::OperatorType(_) => {
+                let (remaining_string, packet) =
+                    OperatorPacket::from_string(header, packet_binary_string)?;
+                return Ok((remaining_string, Some(Packet::Operator(packet))));
+            }
+        }
+    }
+    Ok((None, None))
+}
+
+fn parse_transmission(input: &str) -> Result<Packet, InvalidInput> {
+    // Decode the hexadecimal transmission of the first line into the outermost packet
+    // Errors inside the packets are reported at the hexadecimal digit containing the bit
+    let line = parse::single_line(Day16::DAY, input)?;
+    let binary_string = hex_to_binary_string(&line)?;
+    let error = match parse_packet(&binary_string) {
+        Ok((_, Some(packet))) => return Ok(packet),
+        Ok((_, None)) => PacketError {
+            remaining_bits: binary_string.len(),
+            message: "Transmission too short for a packet",
+        },
+        Err(error) => error,
+    };
+    let bit_index = binary_string.len() - error.remaining_bits;
+    let digit = line
+        .text
+        .get(bit_index / 4..bit_index / 4 + 1)
+        .unwrap_or("");
+    Err(line
+        .error(
+            if digit.is_empty() {
+                &line.text[line.text.len()..]
+            } else {
+                digit
+            },
+            &format!("{} at bit {}, digit", error.message, bit_index),
+        )
+        .into())
+}
+
+// Values of generated packets stay below this limit, so their evaluation never overflows
+const GENERATED_VALUE_LIMIT: i64 = 1 << 48;
+
+fn generate_packet(rng: &mut Rng, literals: usize) -> (String, i64) {
+    // Random packet with the given number of literal value packets, returned as bits together with its value
+    let version = rng.below(8);
+    if literals <= 1 {
+        // The value is split into groups of 4 bits, all groups except the last start with a 1 bit
+        let value = rng.range(0..=0xffff);
+        let group_count = usize::max(1, (64 - value.leading_zeros() as usize).div_ceil(4));
+        let groups = (0..group_count)
+            .rev()
+            .map(|group| {
+                let last_group = if group == 0 { 0 } else { 1 };
+                format!("{}{:04b}", last_group, (value >> (4 * group)) & 0xf)
+            })
+            .collect::<String>();
+        return (format!("{:03b}100{}", version, groups), value);
+    }
+
+    // Comparisons need exactly two sub-packets, the literals are distributed randomly over the sub-packets
+    let type_id = *rng.choose(&[0, 1, 2, 3, 5, 6, 7]);
+    let sub_packet_count = match type_id {
+        5..=7 => 2,
+        _ => rng.range(1..=usize::min(literals, 4) as i64) as usize,
+    };
+    let mut sub_packet_literals = vec![1; sub_packet_count];
+    for _ in sub_packet_count..literals {
+        sub_packet_literals[rng.below(sub_packet_count)] += 1;
+    }
+    let (sub_packets, values): (Vec<String>, Vec<i64>) = sub_packet_literals
+        .into_iter()
+        .map(|literals| generate_packet(rng, literals))
+        .unzip();
+
+    // Products and sums exceeding the value limit are replaced by sums and maximums
+    let product = values.iter().try_fold(1, |product: i64, value| {
+        product
+            .checked_mul(*value)
+            .filter(|product| *product < GENERATED_VALUE_LIMIT)
+    });
+    let sum = values.iter().sum::<i64>();
+    let (type_id, value) = match (type_id, product) {
+        (1, Some(product)) => (1, product),
+        (0 | 1, _) if sum < GENERATED_VALUE_LIMIT => (0, sum),
+        (0 | 1 | 3, _) => (3, *values.iter().max().unwrap()),
+        (2, _) => (2, *values.iter().min().unwrap()),
+        (5, _) => (5, (values[0] > values[1]) as i64),
+        (6, _) => (6, (values[0] < values[1]) as i64),
+        _ => (7, (values[0] == values[1]) as i64),
+    };
+
+    // The sub-packets are given either by their total length in bits or by their number
+    let sub_packets = sub_packets.concat();
+    let length = if sub_packets.len() < 1 << 15 && rng.chance(0.5) {
+        format!("0{:015b}", sub_packets.len())
+    } else {
+        format!("1{:011b}", sub_packet_count)
+    };
+    (
+        format!("{:03b}{:03b}{}{}", version, type_id, length, sub_packets),
+        value,
+    )
+}
+
+fn generate_transmission(rng: &mut Rng, size: usize) -> String {
+    // The size is the number of literal value packets in the packet hierarchy
+    let (bits, _) = generate_packet(rng, size);
+    // The bits are padded with zeros to complete the last hexadecimal digit
+    let hexadecimal = bits
+        .as_bytes()
+        .chunks(4)
+        .map(|digit_bits| {
+            let digit = (0..4).fold(0, |digit, bit| {
+                2 * digit + digit_bits.get(bit).map_or(0, |bit| (bit - b'0') as u32)
+            });
+            char::from_digit(digit, 16).unwrap().to_ascii_uppercase()
+        })
+        .collect::<String>();
+    format!("{}\n", hexadecimal)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u32 = 16;
+    const TITLE: &'static str = "Packet Decoder";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Packet;
+
+    fn parse(input: &str) -> Result<Packet, InvalidInput> {
+        parse_transmission(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_transmission(rng, size)
+    }
+
+    fn part_one(packet: &Packet, _: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(packet.get_version_number()))
+    }
+
+    fn part_two(packet: &Packet, _: &Parameters) -> Result<Answer, String> {
+        packet
+            .evaluate_expression()
+            .map(Answer::new)
+            .ok_or_else(|| String::from("The value of the transmission exceeds the range of i64"))
+    }
+}
@@ -0,0 +1,139 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Solution};
+
+// Example target area from the puzzle description, used with --example
+const EXAMPLE: &str = "target area: x=20..30, y=-10..-5";
+
+fn parse_range(line: &InputLine, range: &str, axis: &str) -> Result<(i32, i32), InvalidInput> {
+    // Parse a range of the form axis=from..to
+    let (name, bounds) = line.split_once(range, "=")?;
+    if name != axis {
+        let message = format!("Expected the {} axis instead of", axis);
+        return Err(line.error(name, &message).into());
+    }
+    let (c_0, c_1) = line.split_once(bounds, "..")?;
+    let (c_0, c_1) = parse::combine(
+        line.parse(c_0, "coordinate").map_err(InvalidInput::from),
+        line.parse(c_1, "coordinate").map_err(InvalidInput::from),
+    )?;
+    if c_0 > c_1 {
+        return Err(line
+            .error(bounds, "Range has to start at its lower end, found")
+            .into());
+    }
+    Ok((c_0, c_1))
+}
+
+fn parse_input_data(target_coordinates: &str) -> Result<(i32, i32, i32, i32), InvalidInput> {
+    // Read bounds coordinates of the target area from input
+    // The search for velocities expects the target area ahead of and below the launch position
+    let line = parse::single_line(Day17::DAY, target_coordinates)?;
+    let (_, ranges) = line.split_once(line.text.trim(), ": ")?;
+    let (x_range, y_range) = line.split_once(ranges, ", ")?;
+    let ((x_0, x_1), (y_0, y_1)) = parse::combine(
+        parse_range(&line, x_range, "x"),
+        parse_range(&line, y_range, "y"),
+    )?;
+    let mut violations = Vec::new();
+    if x_0 < 0 {
+        violations.push(line.error(
+            x_range,
+            "Target area has to be ahead of the launch position, found",
+        ));
+    }
+    if y_1 >= 0 {
+        violations.push(line.error(
+            y_range,
+            "Target area has to be below the launch position, found",
+        ));
+    }
+    InvalidInput::from_violations(violations)?;
+    Ok((x_0, x_1, y_0, y_1))
+}
+
+fn follow_trajectory(
+    velocity: (i32, i32),
+    target: (i32, i32, i32, i32),
+) -> Option<(i32, i32, i32)> {
+    // Simulate the given trajectory in discrete steps and check if the target area is found
+    let (mut x, mut y) = (0, 0);
+    let mut max_y = 0;
+    let (mut v_x, mut v_y) = velocity;
+    let (t_x_0, t_x_1, t_y_0, t_y_1) = target;
+    let right_bound = i32::max(t_x_0, t_x_1);
+    let lower_bound = i32::min(t_y_0, t_y_1);
+    let (t_x, t_y) = (t_x_0..=t_x_1, t_y_0..=t_y_1);
+    while x <= right_bound && y >= lower_bound {
+        x += v_x;
+        y += v_y;
+        v_x = match v_x {
+            i32::MIN..=-1 => v_x + 1,
+            1..=i32::MAX => v_x - 1,
+            0 => 0,
+        };
+        v_y -= 1;
+        max_y = i32::max(max_y, y);
+        if t_x.contains(&x) && t_y.contains(&y) {
+            return Some((velocity.0, velocity.1, max_y));
+        }
+    }
+
+    None
+}
+
+fn find_trajectories(target: (i32, i32, i32, i32)) -> Vec<(i32, i32, i32)> {
+    // Find all trajectories that reach the target area
+    let mut trajectories = Vec::new();
+    for v_x in 0..=(target.1) {
+        let y_distance = i32::max(target.2.abs(), target.3.abs());
+        for v_y in (-y_distance)..=y_distance {
+            if let Some(trajectory) = follow_trajectory((v_x, v_y), target) {
+                trajectories.push(trajectory);
+            }
+        }
+    }
+    trajectories
+}
+
+fn generate_target_area(rng: &mut Rng, size: usize) -> String {
+    // The size is the distance to the near edge of the target area, it is about 250 in the puzzle
+    // The target area is ahead of and below the launch position with similar proportions
+    let size = usize::max(size, 1) as i64;
+    let x_0 = size + rng.range(0..=size / 4);
+    let x_1 = x_0 + rng.range(size / 10..=size / 4);
+    let y_1 = -rng.range(size / 5 + 1..=size / 3 + 1);
+    let y_0 = y_1 - rng.range(size / 10..=size / 6);
+    format!("target area: x={}..{}, y={}..{}\n", x_0, x_1, y_0, y_1)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u32 = 17;
+    const TITLE: &'static str = "Trick Shot";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = (i32, i32, i32, i32);
+
+    fn parse(input: &str) -> Result<(i32, i32, i32, i32), InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_target_area(rng, size)
+    }
+
+    fn part_one(target_area: &(i32, i32, i32, i32), _: &Parameters) -> Result<Answer, String> {
+        let trajectories = find_trajectories(*target_area);
+        match trajectories.iter().max_by_key(|trajectory| trajectory.2) {
+            Some((v_x, v_y, max_y)) => Ok(Answer::new(max_y).with_extra("velocity", (*v_x, *v_y))),
+            None => Err(String::from("No trajectory reaches the target area")),
+        }
+    }
+
+    fn part_two(target_area: &(i32, i32, i32, i32), _: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(find_trajectories(*target_area).len()))
+    }
+}
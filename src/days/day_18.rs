@@ -0,0 +1,330 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::{debug, info};
+
+// Example snailfish homework from the puzzle description, used with --example
+const EXAMPLE: &str = "[[[0,[5,8]],[[1,7],[9,6]]],[[4,[1,2]],[[1,4],2]]]
+[[[5,[2,8]],4],[5,[[9,9],0]]]
+[6,[[[6,2],[5,6]],[[7,6],[4,7]]]]
+[[[6,[0,7]],[0,9]],[4,[9,[9,0]]]]
+[[[7,[6,4]],[3,[1,3]]],[[[5,5],1],9]]
+[[6,[[7,3],[3,2]]],[[[3,8],[5,7]],4]]
+[[[[5,4],[7,7]],8],[[8,3],8]]
+[[9,3],[[9,9],[6,[4,9]]]]
+[[2,[[7,7],7]],[[5,8],[[9,3],[0,2]]]]
+[[[[5,2],5],[8,[3,7]]],[[5,[7,5]],[4,4]]]";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum SnailfishNumberToken {
+    PairStart,
+    PairEnd,
+    Number(u32),
+}
+
+fn parse_snailfish_numbers(line: &InputLine) -> Result<Vec<SnailfishNumberToken>, ParseError> {
+    // Parse a Snailfish number string into a Vector of tokens
+    // A stack of the expected tokens ensures that every pair has exactly two elements
+    enum Expected {
+        Element,
+        Separator,
+        PairEnd,
+    }
+    let mut tokens = Vec::new();
+    let mut expected = vec![Expected::Element];
+    for (index, c) in line.text.char_indices() {
+        match (expected.pop(), c) {
+            (Some(Expected::Element), '[') => {
+                // Reduction only explodes pairs of regular numbers, deeper pairs can not be reduced
+                let depth = expected
+                    .iter()
+                    .filter(|token| matches!(token, Expected::PairEnd))
+                    .count();
+                if depth >= 4 {
+                    return Err(line.error(
+                        &line.text[index..index + 1],
+                        "Pairs can be nested at most 4 levels deep, found",
+                    ));
+                }
+                tokens.push(SnailfishNumberToken::PairStart);
+                expected.extend([
+                    Expected::PairEnd,
+                    Expected::Element,
+                    Expected::Separator,
+                    Expected::Element,
+                ]);
+            }
+            (Some(Expected::Element), '0'..='9') => {
+                tokens.push(SnailfishNumberToken::Number(line.digit(index, c, 10)?))
+            }
+            (Some(Expected::Separator), ',') => {}
+            (Some(Expected::PairEnd), ']') => tokens.push(SnailfishNumberToken::PairEnd),
+            (None, _) => {
+                return Err(line.error(
+                    &line.text[index..],
+                    "Unexpected text after snailfish number",
+                ))
+            }
+            _ => return Err(line.error(&line.text[index..index + c.len_utf8()], "Unexpected char")),
+        }
+    }
+    if !expected.is_empty() {
+        return Err(line.end_error("Incomplete snailfish number"));
+    }
+    Ok(tokens)
+}
+
+fn format_snailfish_number(snailfish_number: &[SnailfishNumberToken]) -> String {
+    // Elements of a pair are separated by a comma, an element ends with a number or a closed pair
+    let mut text = String::new();
+    let mut element_ended = false;
+    for token in snailfish_number {
+        match token {
+            SnailfishNumberToken::PairStart | SnailfishNumberToken::Number(_) if element_ended => {
+                text.push(',')
+            }
+            _ => (),
+        }
+        match token {
+            SnailfishNumberToken::PairStart => text.push('['),
+            SnailfishNumberToken::PairEnd => text.push(']'),
+            SnailfishNumberToken::Number(n) => text.push_str(&n.to_string()),
+        }
+        element_ended = !matches!(token, SnailfishNumberToken::PairStart);
+    }
+    text
+}
+
+pub fn add_snailfish_numbers(
+    number_1: Vec<SnailfishNumberToken>,
+    number_2: Vec<SnailfishNumberToken>,
+) -> Vec<SnailfishNumberToken> {
+    // Create a new snailfish number as the sum by setting the two terms as first and second pair element
+    let mut snailfish_number_sum = Vec::new();
+    snailfish_number_sum.push(SnailfishNumberToken::PairStart);
+    snailfish_number_sum.extend(number_1.clone());
+    snailfish_number_sum.extend(number_2.clone());
+    snailfish_number_sum.push(SnailfishNumberToken::PairEnd);
+
+    // Apply explosions and splits as long as the sum is not reduced sufficiently
+    let mut is_valid_number = false;
+    while !is_valid_number {
+        is_valid_number = true;
+
+        // Check for Snailfish number explodes
+        let mut nesting_depth_counter = 0;
+        let mut exploding_pair_indices: Option<(usize, usize)> = None;
+        for (i, token) in snailfish_number_sum.iter().cloned().enumerate() {
+            match token {
+                SnailfishNumberToken::PairStart => {
+                    nesting_depth_counter += 1;
+                }
+                SnailfishNumberToken::PairEnd => {
+                    nesting_depth_counter -= 1;
+                }
+                SnailfishNumberToken::Number(_) if nesting_depth_counter > 4 => {
+                    exploding_pair_indices = Some((i, i + 1));
+                    break;
+                }
+                _ => (),
+            }
+        }
+        if let Some((left_index, right_index)) = exploding_pair_indices {
+            // Explosion necessary
+            let mut snailfish_number_sum_after_explosion = Vec::new();
+            snailfish_number_sum_after_explosion
+                .extend(snailfish_number_sum[..(left_index - 1)].iter().cloned());
+
+            // Add first exploding pair element to next number to the left
+            if let SnailfishNumberToken::Number(explosion_left_number) =
+                snailfish_number_sum[left_index]
+            {
+                for i in (0..=(left_index - 2)).rev() {
+                    if let SnailfishNumberToken::Number(n) = snailfish_number_sum_after_explosion[i]
+                    {
+                        snailfish_number_sum_after_explosion[i] =
+                            SnailfishNumberToken::Number(n + explosion_left_number);
+                        break;
+                    }
+                }
+            }
+
+            // Add a 0 as a replacement for the exploded pair
+            snailfish_number_sum_after_explosion.push(SnailfishNumberToken::Number(0));
+
+            // Add second exploding pair element to next number to the right
+            if let SnailfishNumberToken::Number(explosion_right_number) =
+                snailfish_number_sum[right_index]
+            {
+                for token in snailfish_number_sum.iter_mut().skip(right_index + 1) {
+                    if let SnailfishNumberToken::Number(n) = token {
+                        *token = SnailfishNumberToken::Number(*n + explosion_right_number);
+                        break;
+                    }
+                }
+            }
+            snailfish_number_sum_after_explosion
+                .extend(snailfish_number_sum[(right_index + 2)..].iter().cloned());
+
+            debug!(
+                "Explode {}: {}",
+                format_snailfish_number(
+                    &snailfish_number_sum[(left_index - 1)..=(right_index + 1)]
+                ),
+                format_snailfish_number(&snailfish_number_sum_after_explosion)
+            );
+            is_valid_number = false;
+            snailfish_number_sum = snailfish_number_sum_after_explosion;
+            continue;
+        }
+
+        // Check for Snailfish number splits
+        let mut executed_split = false;
+        snailfish_number_sum = snailfish_number_sum
+            .iter()
+            .cloned()
+            .flat_map(|token| match token {
+                SnailfishNumberToken::Number(n) if n > 9 && !executed_split => {
+                    is_valid_number = false;
+                    executed_split = true;
+                    let n = n as f32;
+                    vec![
+                        SnailfishNumberToken::PairStart,
+                        SnailfishNumberToken::Number((n / 2.0).floor() as u32),
+                        SnailfishNumberToken::Number((n / 2.0).ceil() as u32),
+                        SnailfishNumberToken::PairEnd,
+                    ]
+                }
+                _ => vec![token],
+            })
+            .collect();
+        if executed_split {
+            debug!("Split: {}", format_snailfish_number(&snailfish_number_sum));
+        }
+    }
+    snailfish_number_sum
+}
+
+fn split_snailfish_number_pair(
+    snailfish_number: Vec<SnailfishNumberToken>,
+) -> (Vec<SnailfishNumberToken>, Vec<SnailfishNumberToken>) {
+    // Split a (possibly nested) snailfish number into the two pair elements
+    let mut depth_counter = 0;
+    let mut pair_middle = 0;
+    for (i, token) in snailfish_number.iter().enumerate() {
+        match token {
+            SnailfishNumberToken::PairStart => {
+                depth_counter += 1;
+            }
+            SnailfishNumberToken::PairEnd => {
+                depth_counter -= 1;
+                if depth_counter == 0 {
+                    pair_middle = i;
+                    break;
+                }
+            }
+            SnailfishNumberToken::Number(_) if depth_counter == 0 => {
+                pair_middle = i;
+                break;
+            }
+            _ => (),
+        }
+    }
+    (
+        snailfish_number[..=pair_middle].to_vec(),
+        snailfish_number[(pair_middle + 1)..].to_vec(),
+    )
+}
+
+fn calculate_magnitude(snailfish_number: Vec<SnailfishNumberToken>) -> u32 {
+    // Calculate the magnitude of a snailfish number
+    if snailfish_number.len() == 1 {
+        if let SnailfishNumberToken::Number(n) = snailfish_number[0] {
+            return n;
+        } else {
+            return 0;
+        }
+    }
+    let snailfish_number = &snailfish_number.clone()[1..snailfish_number.len() - 1];
+
+    let (first, second) = split_snailfish_number_pair(snailfish_number.to_vec());
+
+    3 * calculate_magnitude(first) + 2 * calculate_magnitude(second)
+}
+
+fn generate_snailfish_number(rng: &mut Rng, depth: usize) -> String {
+    // Reduced snailfish numbers have pairs nested inside of at most 3 pairs and regular numbers below 10
+    if depth == 0 || (depth < 4 && rng.chance(0.65)) {
+        let left = generate_snailfish_number(rng, depth + 1);
+        let right = generate_snailfish_number(rng, depth + 1);
+        format!("[{},{}]", left, right)
+    } else {
+        rng.below(10).to_string()
+    }
+}
+
+fn generate_homework(rng: &mut Rng, size: usize) -> String {
+    // At least two numbers are needed for the largest sum of two different numbers
+    (0..usize::max(size, 2))
+        .map(|_| format!("{}\n", generate_snailfish_number(rng, 0)))
+        .collect()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u32 = 18;
+    const TITLE: &'static str = "Snailfish";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Vec<SnailfishNumberToken>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec<SnailfishNumberToken>>, InvalidInput> {
+        let snailfish_numbers =
+            parse::parse_lines(Day18::DAY, input, |line| parse_snailfish_numbers(&line))?;
+        if snailfish_numbers.is_empty() {
+            return Err(parse::end_of_input(Day18::DAY, input)
+                .error("", "Missing snailfish numbers")
+                .into());
+        }
+        Ok(snailfish_numbers)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_homework(rng, size)
+    }
+
+    fn part_one(
+        snailfish_numbers: &Vec<Vec<SnailfishNumberToken>>,
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        let snailfish_number_sum = snailfish_numbers
+            .iter()
+            .cloned()
+            .reduce(|sum, snailfish_number| {
+                let sum = add_snailfish_numbers(sum, snailfish_number);
+                info!("Sum: {}", format_snailfish_number(&sum));
+                sum
+            })
+            .unwrap();
+        Ok(Answer::new(calculate_magnitude(snailfish_number_sum)))
+    }
+
+    fn part_two(
+        snailfish_numbers: &Vec<Vec<SnailfishNumberToken>>,
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        let mut max_magnitude = 0;
+        for (i, n_1) in snailfish_numbers.iter().cloned().enumerate() {
+            for (j, n_2) in snailfish_numbers.iter().cloned().enumerate() {
+                if i != j {
+                    let snailfish_number_sum = add_snailfish_numbers(n_1.clone(), n_2);
+                    let sum_magnitude = calculate_magnitude(snailfish_number_sum);
+                    max_magnitude = u32::max(sum_magnitude, max_magnitude);
+                }
+            }
+        }
+        Ok(Answer::new(max_magnitude))
+    }
+}
@@ -0,0 +1,494 @@
+use crate::generate::Rng;
+use crate::geometry::{Aabb, Rotation, Vec3};
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Solution};
+use crate::{debug, info};
+use std::collections::{BTreeMap, HashSet};
+use std::sync::OnceLock;
+
+// Example scanner reports from the puzzle description, used with --example
+const EXAMPLE: &str = "--- scanner 0 ---
+404,-588,-901
+528,-643,409
+-838,591,734
+390,-675,-793
+-537,-823,-458
+-485,-357,347
+-345,-311,381
+-661,-816,-575
+-876,649,763
+-618,-824,-621
+553,345,-567
+474,580,667
+-447,-329,318
+-584,868,-557
+544,-627,-890
+564,392,-477
+455,729,728
+-892,524,684
+-689,845,-530
+423,-701,434
+7,-33,-71
+630,319,-379
+443,580,662
+-789,900,-551
+459,-707,401
+
+--- scanner 1 ---
+686,422,578
+605,423,415
+515,917,-361
+-336,658,858
+95,138,22
+-476,619,847
+-340,-569,-846
+567,-361,727
+-460,603,-452
+669,-402,600
+729,430,532
+-500,-761,534
+-322,571,750
+-466,-666,-811
+-429,-592,574
+-355,545,-477
+703,-491,-529
+-328,-685,520
+413,935,-424
+-391,539,-444
+586,-435,557
+-364,-763,-893
+807,-499,-711
+755,-354,-619
+553,889,-390
+
+--- scanner 2 ---
+649,640,665
+682,-795,504
+-784,533,-524
+-644,584,-595
+-588,-843,648
+-30,6,44
+-674,560,763
+500,723,-460
+609,671,-379
+-555,-800,653
+-675,-892,-343
+697,-426,-610
+578,704,681
+493,664,-388
+-671,-858,530
+-667,343,800
+571,-461,-707
+-138,-166,112
+-889,563,-600
+646,-828,498
+640,759,510
+-630,509,768
+-681,-892,-333
+673,-379,-804
+-742,-814,-386
+577,-820,562
+
+--- scanner 3 ---
+-589,542,597
+605,-692,669
+-500,565,-823
+-660,373,557
+-458,-679,-417
+-488,449,543
+-626,468,-788
+338,-750,-386
+528,-832,-391
+562,-778,733
+-938,-730,414
+543,643,-506
+-524,371,-870
+407,773,750
+-104,29,83
+378,-903,-323
+-778,-728,485
+426,699,580
+-438,-605,-362
+-469,-447,-387
+509,732,623
+647,635,-688
+-868,-804,481
+614,-800,639
+595,780,-596
+
+--- scanner 4 ---
+727,592,562
+-293,-554,779
+441,611,-461
+-714,465,-776
+-743,427,-804
+-660,-479,-426
+832,-632,460
+927,-485,-438
+408,393,-506
+466,436,-512
+110,16,151
+-258,-428,682
+-393,719,612
+-211,-452,876
+808,-476,-593
+-575,615,604
+-485,667,467
+-680,325,-822
+-627,-443,-432
+872,-547,-609
+833,512,582
+807,604,487
+839,-516,451
+891,-625,532
+-652,-548,-490
+30,-46,-14";
+
+fn parse_beacon(line: &InputLine) -> Result<Vec3, InvalidInput> {
+    let point = parse::collect_all(
+        line.text
+            .split(',')
+            .map(|coordinate| line.parse::<i64>(coordinate, "coordinate")),
+    )?;
+    if point.len() != 3 {
+        return Err(line.error(line.text, "Expected 3 coordinates in").into());
+    }
+    Ok(Vec3::new(point[0], point[1], point[2]))
+}
+
+fn parse_input_data(input_data: &str) -> Result<Vec<Scanner>, InvalidInput> {
+    // Parse beacon measurements from input string and create Scanner structs accordingly
+    let mut scanner_measurements = Vec::new();
+    let mut current_scanner_measurements = Vec::new();
+    let mut scanner_number = 0;
+    let mut violations = Vec::new();
+    for line in parse::lines(Day19::DAY, input_data) {
+        let input_line = line.text;
+        if input_line.contains("---") {
+            if !current_scanner_measurements.is_empty() {
+                scanner_measurements
+                    .push(Scanner::new(scanner_number, current_scanner_measurements));
+                scanner_number += 1;
+                current_scanner_measurements = Vec::new();
+            }
+        } else if !input_line.is_empty() {
+            match parse_beacon(&line) {
+                Ok(beacon) => current_scanner_measurements.push(beacon),
+                Err(error) => violations.extend(error.violations),
+            }
+        }
+    }
+    InvalidInput::from_violations(violations)?;
+    if !current_scanner_measurements.is_empty() {
+        scanner_measurements.push(Scanner::new(scanner_number, current_scanner_measurements));
+    }
+    if scanner_measurements.is_empty() {
+        return Err(parse::end_of_input(Day19::DAY, input_data)
+            .error("", "Missing scanner measurements")
+            .into());
+    }
+    Ok(scanner_measurements)
+}
+
+#[derive(Clone)]
+pub struct Scanner {
+    number: i32,
+    position: Vec3,
+    beacon_measurements: Vec<Vec3>,
+}
+
+impl Scanner {
+    fn new(number: i32, beacon_measurements: Vec<Vec3>) -> Scanner {
+        Scanner {
+            number,
+            position: Vec3::ZERO,
+            beacon_measurements,
+        }
+    }
+
+    fn fix_position(&self, position: Vec3, rotation: Rotation) -> Scanner {
+        // Set the position of a scanner after alignment and translate all measurement vectors
+        // Progress goes to the log, the library never writes to stdout
+        debug!("Fixed position of scanner {} to {}", self.number, position);
+        Scanner {
+            number: self.number,
+            position,
+            beacon_measurements: self
+                .beacon_measurements
+                .iter()
+                .map(|measurement| measurement.rotate(rotation) + position)
+                .collect::<Vec<Vec3>>(),
+        }
+    }
+}
+
+// Scanners of the input, both parts share the alignment of the first solved part
+// The time of the alignment counts towards that part
+pub struct ScannerReports {
+    scanners: Vec<Scanner>,
+    alignment: OnceLock<(usize, Result<Vec<Scanner>, String>)>,
+}
+
+impl ScannerReports {
+    pub fn scanners(&self) -> &[Scanner] {
+        &self.scanners
+    }
+
+    fn aligned_scanners(&self, threshold: usize) -> Result<Vec<Scanner>, String> {
+        // Only the alignment of the first threshold is kept, another threshold aligns the scanners again
+        let (aligned_threshold, alignment) = self
+            .alignment
+            .get_or_init(|| (threshold, align_scanners(&self.scanners, threshold)));
+        if *aligned_threshold == threshold {
+            alignment.clone()
+        } else {
+            align_scanners(&self.scanners, threshold)
+        }
+    }
+}
+
+fn get_vectors_between_measurements(measurements: &[Vec3]) -> BTreeMap<Vec3, Vec3> {
+    // Create vectors between all combinations of two measurement vectors in all possible rotations
+    // The vectors are ordered, so the common measurements and the alignment do not depend on hashing
+    let mut v = BTreeMap::new();
+    for (i, m_1) in measurements.iter().cloned().enumerate() {
+        for (j, m_2) in measurements.iter().cloned().enumerate() {
+            if i != j {
+                let rel_vec = m_2 - m_1;
+                for rotation in Rotation::all() {
+                    v.insert(rel_vec.rotate(rotation), m_1);
+                }
+            }
+        }
+    }
+    v
+}
+
+fn get_common_measurements(
+    measurements_1: &BTreeMap<Vec3, Vec3>,
+    measurements_2: &BTreeMap<Vec3, Vec3>,
+) -> Vec<(Vec3, Vec3)> {
+    // Check both scanners for (rotated) vectors between two measurement vectors
+    let mut coordinate_1 = HashSet::new();
+    let mut coordinate_2 = HashSet::new();
+    measurements_1
+        .keys()
+        .cloned()
+        .filter(|v| measurements_2.contains_key(v))
+        .map(|v| {
+            (
+                *measurements_1.get(&v).unwrap(),
+                *measurements_2.get(&v).unwrap(),
+            )
+        })
+        .filter(|(v_1, v_2)| {
+            let add_pair = !(coordinate_1.contains(v_1) && coordinate_2.contains(v_2));
+            coordinate_1.insert(*v_1);
+            coordinate_2.insert(*v_2);
+            add_pair
+        })
+        .collect()
+}
+
+pub fn align_scanner(
+    fixed_scanner: &Vec<Scanner>,
+    scanner_for_alignment: &Scanner,
+    threshold: usize,
+) -> Option<Scanner> {
+    // Search a scanner that has enough shared beacon measurement vectors for alignment via the shared vectors
+    for scanner in fixed_scanner {
+        let common_measurements = get_common_measurements(
+            &get_vectors_between_measurements(&(scanner.beacon_measurements.clone())),
+            &get_vectors_between_measurements(&(scanner_for_alignment.beacon_measurements)),
+        );
+
+        if common_measurements.len() >= threshold {
+            for rotation in Rotation::all() {
+                let distances = common_measurements
+                    .iter()
+                    .map(|(m_1, m_2)| *m_1 - m_2.rotate(rotation))
+                    .collect::<Vec<Vec3>>();
+                if distances.windows(2).all(|d| d[0] == d[1]) {
+                    return Some(scanner_for_alignment.fix_position(distances[0], rotation));
+                }
+            }
+        }
+    }
+    None
+}
+
+pub fn align_scanners(scanners: &[Scanner], threshold: usize) -> Result<Vec<Scanner>, String> {
+    let mut fixed_scanners = vec![scanners[0].clone()];
+    let mut scanners_to_be_aligned = scanners[1..].to_vec();
+
+    // Align all scanners progressively starting with fixing the position of scanner o to (0, 0, 0)
+    // Each pass tries the remaining scanners in the order of their numbers, a scanner can align to one fixed in the same pass
+    // A pass without progress ends the alignment, e.g. for a higher threshold than the input supports
+    while !scanners_to_be_aligned.is_empty() {
+        let remaining = scanners_to_be_aligned.len();
+        let mut unaligned = Vec::new();
+        for scanner_to_be_aligned in scanners_to_be_aligned {
+            match align_scanner(&fixed_scanners, &scanner_to_be_aligned, threshold) {
+                Some(aligned_scanner) => {
+                    fixed_scanners.push(aligned_scanner);
+                    info!(
+                        "{} scanners are left for alignment",
+                        scanners.len() - fixed_scanners.len()
+                    );
+                }
+                None => unaligned.push(scanner_to_be_aligned),
+            }
+        }
+        scanners_to_be_aligned = unaligned;
+        if scanners_to_be_aligned.len() == remaining {
+            break;
+        }
+    }
+    // Beacons of unaligned scanners are missing, so there is no answer without all scanners
+    if !scanners_to_be_aligned.is_empty() {
+        let unaligned = scanners_to_be_aligned
+            .iter()
+            .map(|scanner| scanner.number)
+            .collect::<Vec<i32>>();
+        return Err(format!(
+            "Scanners {} share less than {} beacons with the aligned scanners",
+            unaligned
+                .iter()
+                .map(i32::to_string)
+                .collect::<Vec<String>>()
+                .join(", "),
+            threshold
+        ));
+    }
+    Ok(fixed_scanners)
+}
+
+fn scanner_positions(fixed_scanners: &[Scanner]) -> Vec<Vec3> {
+    // Positions of the aligned scanners ordered by scanner number
+    let mut scanners = fixed_scanners.iter().collect::<Vec<&Scanner>>();
+    scanners.sort_by_key(|scanner| scanner.number);
+    scanners.iter().map(|scanner| scanner.position).collect()
+}
+
+fn scanner_range(position: Vec3) -> Aabb {
+    // Scanners detect all beacons at most 1000 units away on each axis
+    let range = Vec3::new(1000, 1000, 1000);
+    Aabb::new(position - range, position + range)
+}
+
+fn random_point(rng: &mut Rng, area: &Aabb) -> Vec3 {
+    Vec3::new(
+        rng.range(area.min.x..=area.max.x),
+        rng.range(area.min.y..=area.max.y),
+        rng.range(area.min.z..=area.max.z),
+    )
+}
+
+fn generate_scanner_reports(rng: &mut Rng, size: usize) -> String {
+    // The size is the number of scanners
+    // The scanners form a chain, each one shares at least 12 beacons with its predecessor to allow the alignment
+    let mut positions = vec![Vec3::ZERO];
+    let mut beacons = Vec::new();
+    for _ in 1..size {
+        let previous_position = *positions.last().unwrap();
+        let position = previous_position
+            + Vec3::new(
+                rng.range(-1200..=1200),
+                rng.range(-1200..=1200),
+                rng.range(-1200..=1200),
+            );
+        let overlap = scanner_range(previous_position)
+            .intersection(&scanner_range(position))
+            .unwrap();
+        beacons.extend((0..12).map(|_| random_point(rng, &overlap)));
+        positions.push(position);
+    }
+    for position in positions.iter() {
+        let range = scanner_range(*position);
+        for _ in 0..rng.range(5..=15) {
+            beacons.push(random_point(rng, &range));
+        }
+    }
+    beacons.sort();
+    beacons.dedup();
+
+    // Each scanner reports all beacons in its range relative to itself in its own random orientation
+    let rotations = Rotation::all().collect::<Vec<Rotation>>();
+    let mut text = String::new();
+    for (number, position) in positions.into_iter().enumerate() {
+        let rotation = *rng.choose(&rotations);
+        let range = scanner_range(position);
+        let mut measurements = beacons
+            .iter()
+            .filter(|beacon| range.contains(**beacon))
+            .map(|beacon| (*beacon - position).rotate(rotation))
+            .collect::<Vec<Vec3>>();
+        rng.shuffle(&mut measurements);
+        if number > 0 {
+            text.push('\n');
+        }
+        text.push_str(&format!("--- scanner {} ---\n", number));
+        for measurement in measurements {
+            text.push_str(&format!(
+                "{},{},{}\n",
+                measurement.x, measurement.y, measurement.z
+            ));
+        }
+    }
+    text
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const DAY: u32 = 19;
+    const TITLE: &'static str = "Beacon Scanner";
+    const EXAMPLE: &'static str = EXAMPLE;
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "threshold",
+        description: "Number of shared beacons needed to align two scanners",
+        defaults: [12, 12],
+        minimum: 3,
+        maximum: 1000,
+    }];
+
+    type Input = ScannerReports;
+
+    fn parse(input: &str) -> Result<ScannerReports, InvalidInput> {
+        Ok(ScannerReports {
+            scanners: parse_input_data(input)?,
+            alignment: OnceLock::new(),
+        })
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_scanner_reports(rng, size)
+    }
+
+    fn part_one(reports: &ScannerReports, parameters: &Parameters) -> Result<Answer, String> {
+        let fixed_scanners = reports.aligned_scanners(parameters.get("threshold") as usize)?;
+        let scanner_positions = scanner_positions(&fixed_scanners);
+        let mut beacons = HashSet::new();
+        for scanner in fixed_scanners {
+            beacons.extend(scanner.beacon_measurements);
+        }
+        Ok(Answer::new(beacons.len()).with_extra("scanner_positions", scanner_positions))
+    }
+
+    fn part_two(reports: &ScannerReports, parameters: &Parameters) -> Result<Answer, String> {
+        let fixed_scanners = reports.aligned_scanners(parameters.get("threshold") as usize)?;
+        let mut max_scanner_distance = 0;
+        for (i, scanner_1) in fixed_scanners.iter().enumerate() {
+            for (j, scanner_2) in fixed_scanners.iter().enumerate() {
+                if i != j {
+                    let scanner_distance =
+                        scanner_1.position.manhattan_distance(scanner_2.position);
+                    max_scanner_distance = i64::max(max_scanner_distance, scanner_distance);
+                }
+            }
+        }
+        Ok(Answer::new(max_scanner_distance)
+            .with_extra("scanner_positions", scanner_positions(&fixed_scanners)))
+    }
+}
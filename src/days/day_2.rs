@@ -0,0 +1,425 @@
+use crate::generate::Rng;
+use crate::json::Json;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Part, Solution};
+use std::fmt;
+use std::str::FromStr;
+
+// Example movement commands from the puzzle description, used with --example
+const EXAMPLE: &str = "forward 5
+down 5
+forward 8
+up 3
+down 8
+forward 2";
+
+// Single line of a planned course
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Command {
+    Forward(i32),
+    Up(i32),
+    Down(i32),
+}
+
+// Reason a command is rejected, with the offending text of the command
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CommandError {
+    Empty,
+    UnknownDirection(String),
+    MissingUnits,
+    InvalidUnits(String),
+    SignedUnits(String),
+    NegativeUnits(String),
+    ExtraTokens(String),
+}
+
+impl CommandError {
+    fn description(&self) -> &'static str {
+        match self {
+            CommandError::Empty => "Expected a command, found an empty line",
+            CommandError::UnknownDirection(_) => "Expected forward, up or down instead of",
+            CommandError::MissingUnits => "Missing number of units",
+            CommandError::InvalidUnits(_) => "Invalid number of units",
+            CommandError::SignedUnits(_) => {
+                "Expected a number of units without a + sign instead of"
+            }
+            CommandError::NegativeUnits(_) => "Expected a non-negative number of units instead of",
+            CommandError::ExtraTokens(_) => "Unexpected text after the command",
+        }
+    }
+
+    fn token(&self) -> Option<usize> {
+        // Position of the offending whitespace separated token within the command
+        match self {
+            CommandError::UnknownDirection(_) => Some(0),
+            CommandError::Empty | CommandError::MissingUnits => None,
+            CommandError::InvalidUnits(_)
+            | CommandError::SignedUnits(_)
+            | CommandError::NegativeUnits(_) => Some(1),
+            CommandError::ExtraTokens(_) => Some(2),
+        }
+    }
+}
+
+impl fmt::Display for CommandError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CommandError::Empty | CommandError::MissingUnits => {
+                write!(f, "{}", self.description())
+            }
+            CommandError::UnknownDirection(text)
+            | CommandError::InvalidUnits(text)
+            | CommandError::SignedUnits(text)
+            | CommandError::NegativeUnits(text)
+            | CommandError::ExtraTokens(text) => write!(f, "{} '{}'", self.description(), text),
+        }
+    }
+}
+
+impl std::error::Error for CommandError {}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Same notation as the planned course
+        match self {
+            Command::Forward(n) => write!(f, "forward {}", n),
+            Command::Up(n) => write!(f, "up {}", n),
+            Command::Down(n) => write!(f, "down {}", n),
+        }
+    }
+}
+
+impl FromStr for Command {
+    type Err = CommandError;
+
+    fn from_str(text: &str) -> Result<Command, CommandError> {
+        // A direction followed by a non-negative number of units, nothing else
+        // The units are plain digits, a leading + is rejected although it parses as i32
+        let mut tokens = text.split_whitespace();
+        let direction = tokens.next().ok_or(CommandError::Empty)?;
+        let command: fn(i32) -> Command = match direction {
+            "forward" => Command::Forward,
+            "up" => Command::Up,
+            "down" => Command::Down,
+            _ => return Err(CommandError::UnknownDirection(String::from(direction))),
+        };
+        let units = match tokens.next() {
+            Some(units) if units.starts_with('+') => {
+                return Err(CommandError::SignedUnits(String::from(units)))
+            }
+            Some(units) => units
+                .parse::<i32>()
+                .map_err(|_| CommandError::InvalidUnits(String::from(units)))?,
+            None => return Err(CommandError::MissingUnits),
+        };
+        if units < 0 {
+            return Err(CommandError::NegativeUnits(units.to_string()));
+        }
+        match tokens.collect::<Vec<&str>>() {
+            extra_tokens if extra_tokens.is_empty() => Ok(command(units)),
+            extra_tokens => Err(CommandError::ExtraTokens(extra_tokens.join(" "))),
+        }
+    }
+}
+
+fn parse_command(line: InputLine) -> Result<Command, ParseError> {
+    // The error points to the offending token of the line, extra tokens are marked up to the end of the line
+    line.text.parse::<Command>().map_err(|error| {
+        let token = error
+            .token()
+            .and_then(|i| line.text.split_whitespace().nth(i));
+        match (token, &error) {
+            (Some(token), CommandError::ExtraTokens(_)) => {
+                let start = token.as_ptr() as usize - line.text.as_ptr() as usize;
+                line.error(line.text[start..].trim_end(), error.description())
+            }
+            (Some(token), _) => line.error(token, error.description()),
+            (None, CommandError::Empty) => line.error(&line.text[..0], error.description()),
+            (None, _) => line.end_error(error.description()),
+        }
+    })
+}
+
+fn parse_movement_commands(movements: &str) -> Result<Vec<Command>, InvalidInput> {
+    // The positions of both movement models have to stay in range for the whole course
+    let commands = parse::parse_lines(Day2::DAY, movements, parse_command)?;
+    if let Err(OutOfRange { command }) = execute_submarine_movements(&DirectMovement, &commands)
+        .and(execute_submarine_movements(&AimedMovement, &commands))
+    {
+        let line = parse::lines(Day2::DAY, movements).nth(command - 1).unwrap();
+        return Err(line
+            .error(
+                line.text,
+                "Position of the submarine overflows with command",
+            )
+            .into());
+    }
+    Ok(commands)
+}
+
+// Command of a course that moves the submarine beyond the range of its state, numbered from 1 like the lines
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OutOfRange {
+    pub command: usize,
+}
+
+impl fmt::Display for OutOfRange {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "Position of the submarine overflows with command {}",
+            self.command
+        )
+    }
+}
+
+impl std::error::Error for OutOfRange {}
+
+// Interpretation of the commands, each model tracks its own state of the submarine
+// The state starts at its default, the surface at the start of the course
+pub trait MovementModel {
+    type State: Clone + Default;
+
+    // The state after the command, None if it leaves the range of its numbers
+    fn apply(&self, state: &Self::State, command: Command) -> Option<Self::State>;
+
+    // Horizontal position and depth of a state
+    fn position(&self, state: &Self::State) -> (i64, i64);
+
+    // Aim of a state, models without an aim keep the default
+    fn aim(&self, _state: &Self::State) -> Option<i64> {
+        None
+    }
+}
+
+// Part 1: up and down change the depth directly
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct DirectMovement;
+
+impl MovementModel for DirectMovement {
+    type State = (i64, i64);
+
+    fn apply(&self, &(x, y): &(i64, i64), command: Command) -> Option<(i64, i64)> {
+        match command {
+            Command::Forward(n) => Some((x.checked_add(n as i64)?, y)),
+            Command::Up(n) => Some((x, y.checked_sub(n as i64)?)),
+            Command::Down(n) => Some((x, y.checked_add(n as i64)?)),
+        }
+    }
+
+    fn position(&self, state: &(i64, i64)) -> (i64, i64) {
+        *state
+    }
+}
+
+// Part 2: up and down change the aim, moving forward changes the depth by the aim
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AimedMovement;
+
+impl MovementModel for AimedMovement {
+    type State = (i64, i64, i64);
+
+    fn apply(&self, &(x, y, a): &(i64, i64, i64), command: Command) -> Option<(i64, i64, i64)> {
+        match command {
+            Command::Forward(n) => Some((
+                x.checked_add(n as i64)?,
+                y.checked_add(a.checked_mul(n as i64)?)?,
+                a,
+            )),
+            Command::Up(n) => Some((x, y, a.checked_sub(n as i64)?)),
+            Command::Down(n) => Some((x, y, a.checked_add(n as i64)?)),
+        }
+    }
+
+    fn position(&self, &(x, y, _): &(i64, i64, i64)) -> (i64, i64) {
+        (x, y)
+    }
+
+    fn aim(&self, &(_, _, a): &(i64, i64, i64)) -> Option<i64> {
+        Some(a)
+    }
+}
+
+fn run_course<M: MovementModel>(
+    model: &M,
+    movements: &[Command],
+    mut visit: impl FnMut(usize, &M::State),
+) -> Result<M::State, OutOfRange> {
+    // Visit the state at the start of the course followed by the state after each command
+    let mut state = M::State::default();
+    visit(0, &state);
+    for (i, movement) in movements.iter().enumerate() {
+        state = model
+            .apply(&state, *movement)
+            .ok_or(OutOfRange { command: i + 1 })?;
+        visit(i + 1, &state);
+    }
+    Ok(state)
+}
+
+pub fn execute_submarine_movements<M: MovementModel>(
+    model: &M,
+    movements: &[Command],
+) -> Result<(i64, i64), OutOfRange> {
+    run_course(model, movements, |_, _| {}).map(|state| model.position(&state))
+}
+
+// Position of the submarine after a command, step 0 is the start of the course
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CourseStep {
+    pub step: usize,
+    pub command: Option<Command>,
+    pub horizontal_position: i64,
+    pub depth: i64,
+    pub aim: Option<i64>,
+}
+
+pub fn trace_course<M: MovementModel>(
+    model: &M,
+    movements: &[Command],
+) -> Result<Vec<CourseStep>, OutOfRange> {
+    let mut trace = Vec::new();
+    run_course(model, movements, |step, state| {
+        let (horizontal_position, depth) = model.position(state);
+        trace.push(CourseStep {
+            step,
+            command: step.checked_sub(1).map(|i| movements[i]),
+            horizontal_position,
+            depth,
+            aim: model.aim(state),
+        });
+    })?;
+    Ok(trace)
+}
+
+pub fn trace_csv(trace: &[CourseStep]) -> String {
+    // The step is the line number of the command in the course, models without an aim leave it empty
+    let mut csv = String::from("step,command,horizontal_position,depth,aim\n");
+    for step in trace {
+        csv.push_str(&format!(
+            "{},{},{},{},{}\n",
+            step.step,
+            step.command
+                .map_or(String::new(), |command| command.to_string()),
+            step.horizontal_position,
+            step.depth,
+            step.aim.map_or(String::new(), |aim| aim.to_string())
+        ));
+    }
+    csv
+}
+
+// Key figures of a course for auditing it without the whole trace
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CourseSummary {
+    pub max_depth: i64,
+    // First step reaching the maximum depth
+    pub max_depth_step: usize,
+    // Length of the path of the submarine, moving forward with an aim changes both coordinates at once
+    pub total_distance: f64,
+}
+
+pub fn summarize_course<M: MovementModel>(
+    model: &M,
+    movements: &[Command],
+) -> Result<CourseSummary, OutOfRange> {
+    // The states are summarized one by one, the trace is not kept
+    // Distances are computed in f64, the difference of two i64 positions can exceed the i64 range
+    let mut summary = CourseSummary {
+        max_depth: 0,
+        max_depth_step: 0,
+        total_distance: 0.0,
+    };
+    let mut previous = (0, 0);
+    run_course(model, movements, |step, state| {
+        let (x, y) = model.position(state);
+        if y > summary.max_depth {
+            summary.max_depth = y;
+            summary.max_depth_step = step;
+        }
+        summary.total_distance +=
+            (x as f64 - previous.0 as f64).hypot(y as f64 - previous.1 as f64);
+        previous = (x, y);
+    })?;
+    Ok(summary)
+}
+
+impl From<&CourseSummary> for Json {
+    fn from(summary: &CourseSummary) -> Json {
+        Json::object()
+            .with("max_depth", summary.max_depth)
+            .with("max_depth_step", summary.max_depth_step)
+            .with("total_distance", summary.total_distance)
+    }
+}
+
+fn solve_course<M: MovementModel>(model: &M, movements: &[Command]) -> Result<Answer, String> {
+    // The product of two i64 positions always fits into an i128
+    let (x, y) =
+        execute_submarine_movements(model, movements).map_err(|error| error.to_string())?;
+    let summary = summarize_course(model, movements).map_err(|error| error.to_string())?;
+    Ok(Answer::new(x as i128 * y as i128).with_extra("course", &summary))
+}
+
+fn generate_movement_commands(rng: &mut Rng, size: usize) -> String {
+    // Random commands, the submarine stays between the surface and a depth of 1000
+    // The depth of part 1 is the aim of part 2, so the positions of part 2 only grow linearly with the size
+    let mut depth = 0;
+    (0..size)
+        .map(|_| {
+            let units = rng.range(1..=9);
+            let direction = match rng.below(10) {
+                0..=4 => "forward",
+                5..=6 if depth >= units => {
+                    depth -= units;
+                    "up"
+                }
+                _ if depth + units > 1000 => {
+                    depth -= units;
+                    "up"
+                }
+                _ => {
+                    depth += units;
+                    "down"
+                }
+            };
+            format!("{} {}\n", direction, units)
+        })
+        .collect()
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u32 = 2;
+    const TITLE: &'static str = "Dive!";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Command>;
+
+    fn parse(input: &str) -> Result<Vec<Command>, InvalidInput> {
+        parse_movement_commands(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_movement_commands(rng, size)
+    }
+
+    fn part_one(movement_commands: &Vec<Command>, _: &Parameters) -> Result<Answer, String> {
+        solve_course(&DirectMovement, movement_commands)
+    }
+
+    fn part_two(movement_commands: &Vec<Command>, _: &Parameters) -> Result<Answer, String> {
+        solve_course(&AimedMovement, movement_commands)
+    }
+
+    fn trace(movement_commands: &Vec<Command>, part: Part, _: &Parameters) -> Option<String> {
+        // The parser checked that both models stay in range
+        let trace = match part {
+            Part::One => trace_course(&DirectMovement, movement_commands),
+            Part::Two => trace_course(&AimedMovement, movement_commands),
+        };
+        trace.ok().map(|trace| trace_csv(&trace))
+    }
+}
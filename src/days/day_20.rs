@@ -0,0 +1,249 @@
+use crate::frames::Frame;
+use crate::generate::{self, Rng};
+use crate::grid::Grid;
+use crate::info;
+use crate::netpbm::Image;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Part, Solution};
+
+// Example image enhancement algorithm and input image from the puzzle description, used with --example
+const EXAMPLE: &str = "..#.#..#####.#.#.#.###.##.....###.##.#..###.####..#####..#....#..#..##..###..######.###...####..#..#####..##..#.#####...##.#.#..#.##..#.#......#.###.######.###.####...#.##.##..#..#..#####.....#.#....###..#.##......#.....#..#..#..##..#...##.######.####.####.#.#...#.......#..#.#.#...####.##.#......#..#...##.#.##..#...##.#.##..###.#......#.#.......#.#.#.####.###.##...#.....####.#..#..#.##.#....##..#.####....##...##..#...#......#.#.......#.......##..####..#...#.#.#...##..#.#..###..#####........#..####......#..#
+
+#..#.
+#....
+##..#
+..#..
+..###";
+
+fn parse_pixels(line: &InputLine) -> Result<Vec<bool>, InvalidInput> {
+    // Light pixels are marked with '#' and dark pixels with '.'
+    parse::collect_all(line.text.char_indices().map(|(index, c)| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        _ => Err(line.error(&line.text[index..index + c.len_utf8()], "Invalid pixel")),
+    }))
+}
+
+fn parse_input_data(input_data: &str) -> Result<(Vec<bool>, Grid<bool>), InvalidInput> {
+    // Create the enhancement algorithm data and the image pixels from the input lines
+    // The algorithm needs a value for each of the 512 possible pixel neighborhoods
+    let mut enhancement = Vec::new();
+    let mut image: Vec<Vec<bool>> = Vec::new();
+    let mut enhancement_finished = false;
+    let mut last_line = parse::end_of_input(Day20::DAY, input_data);
+    let mut violations = Vec::new();
+    for line in parse::lines(Day20::DAY, input_data) {
+        last_line = line;
+        let pixels = match parse_pixels(&line) {
+            Ok(pixels) => pixels,
+            Err(error) => {
+                // The count of the pixels is still needed for the checks below
+                violations.extend(error.violations);
+                line.text.chars().map(|c| c == '#').collect()
+            }
+        };
+        if enhancement_finished {
+            if image.first().is_some_and(|row| row.len() != pixels.len()) || pixels.is_empty() {
+                violations.push(line.error(line.text, "Image rows need the same length, found"));
+            }
+            image.push(pixels);
+        } else if pixels.is_empty() {
+            if enhancement.len() != 512 {
+                violations.push(line.error(
+                    line.text,
+                    &format!(
+                        "Expected 512 enhancement values, found {}",
+                        enhancement.len()
+                    ),
+                ));
+            }
+            enhancement_finished = true;
+        } else {
+            enhancement.extend(pixels);
+        }
+    }
+    if image.is_empty() {
+        violations.push(last_line.end_error("Missing image"));
+    }
+    InvalidInput::from_violations(violations)?;
+    // The rows were checked for equal lengths while parsing
+    Ok((enhancement, Grid::from_rows(image).unwrap()))
+}
+
+fn get_enhancement_value(line: Vec<bool>, enhancements: &[bool]) -> bool {
+    // Lookup of the enhancement algorithm value based on the bit values of the surrounding pixels
+    let enhancement_index = line
+        .iter()
+        .fold(0, |index, bit| (index << 1) | usize::from(*bit));
+
+    enhancements[enhancement_index]
+}
+
+fn get_image_value(
+    x: isize,
+    y: isize,
+    image: &Grid<bool>,
+    enhancements: &[bool],
+    enhancement_step: i32,
+) -> bool {
+    // Get the current image value for the actual image and alternating value for the 'infinity' values
+    match image.checked_position(x, y) {
+        Some(position) => image[position],
+        None if enhancements[0] && enhancements[0] != enhancements[enhancements.len() - 1] => {
+            enhancement_step % 2 == 1
+        }
+        None => false,
+    }
+}
+
+pub fn enhance_image(
+    image: &Grid<bool>,
+    enhancements: &[bool],
+    enhancement_step: i32,
+) -> Grid<bool> {
+    // Perform a single enhancement step by enhancing each pixel based on the bit values of its surrounding pixels
+    // The enhanced image grows by one pixel on each side
+    // If the first and the last enhancements bits are different and the first bit is true:
+    // Then pixels in the 'infinity' alternate between lit and dark between each step
+    // This is handled inside of get_image_value
+    Grid::from_fn(image.width() + 2, image.height() + 2, |(x, y)| {
+        let (x, y) = (x as isize - 1, y as isize - 1);
+        let image_pixel_window = (-1..=1)
+            .flat_map(|d_y| (-1..=1).map(move |d_x| (x + d_x, y + d_y)))
+            .map(|(x, y)| get_image_value(x, y, image, enhancements, enhancement_step))
+            .collect();
+        get_enhancement_value(image_pixel_window, enhancements)
+    })
+}
+
+fn multiple_enhancement_steps(
+    enhancement_steps: i32,
+    image: &Grid<bool>,
+    enhancements: &[bool],
+) -> Grid<bool> {
+    let mut enhanced_image = image.clone();
+    for i in 0..enhancement_steps {
+        enhanced_image = enhance_image(&enhanced_image, enhancements, i);
+        info!(
+            "Enhancement step {} lights {} pixels",
+            i + 1,
+            count_lit_pixels(&enhanced_image)
+        );
+    }
+    enhanced_image
+}
+
+fn enhancement_frames(
+    image: &Grid<bool>,
+    enhancements: &[bool],
+    enhancement_steps: i32,
+    emit: &mut dyn FnMut(Frame),
+) {
+    // All frames have the size of the final image to line them up in an animation
+    // The border around the smaller images of earlier steps shows the pixels in the 'infinity'
+    let padding = enhancement_steps as usize;
+    let (width, height) = (image.width() + 2 * padding, image.height() + 2 * padding);
+    let mut enhanced_image = image.clone();
+    for step in 0..=enhancement_steps {
+        if step > 0 {
+            enhanced_image = enhance_image(&enhanced_image, enhancements, step - 1);
+        }
+        let offset = (enhancement_steps - step) as isize;
+        let frame = Grid::from_fn(width, height, |(x, y)| {
+            get_image_value(
+                x as isize - offset,
+                y as isize - offset,
+                &enhanced_image,
+                enhancements,
+                step,
+            )
+        });
+        emit(Frame::image(format!("step {}", step), Image::Bitmap(frame)));
+    }
+}
+
+fn count_lit_pixels(image: &Grid<bool>) -> usize {
+    image.iter().filter(|pixel| **pixel).count()
+}
+
+fn generate_trench_map(rng: &mut Rng, size: usize) -> String {
+    // Random enhancement algorithm and a square image of random pixels
+    // If the dark surroundings light up, they have to turn dark again to keep the number of lit pixels finite
+    let pixel = |lit: bool| if lit { '#' } else { '.' };
+    let mut enhancement = (0..512).map(|_| rng.chance(0.5)).collect::<Vec<bool>>();
+    if enhancement[0] {
+        enhancement[511] = false;
+    }
+    let mut text = enhancement.into_iter().map(pixel).collect::<String>();
+    text.push_str("\n\n");
+    text.push_str(&generate::grid(rng, size, size, |rng| {
+        pixel(rng.chance(0.5))
+    }));
+    text
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u32 = 20;
+    const TITLE: &'static str = "Trench Map";
+    const EXAMPLE: &'static str = EXAMPLE;
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "steps",
+        description: "Number of image enhancement steps",
+        defaults: [2, 50],
+        minimum: 0,
+        maximum: 1000,
+    }];
+
+    type Input = (Vec<bool>, Grid<bool>);
+
+    fn parse(input: &str) -> Result<(Vec<bool>, Grid<bool>), InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_trench_map(rng, size)
+    }
+
+    fn part_one(
+        (enhancements, image): &(Vec<bool>, Grid<bool>),
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let enhanced_image =
+            multiple_enhancement_steps(parameters.get("steps") as i32, image, enhancements);
+        Ok(Answer::new(count_lit_pixels(&enhanced_image)))
+    }
+
+    fn part_two(
+        (enhancements, image): &(Vec<bool>, Grid<bool>),
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let enhanced_image =
+            multiple_enhancement_steps(parameters.get("steps") as i32, image, enhancements);
+        Ok(Answer::new(count_lit_pixels(&enhanced_image)))
+    }
+
+    fn image(
+        (enhancements, image): &(Vec<bool>, Grid<bool>),
+        _: Part,
+        parameters: &Parameters,
+    ) -> Option<Image> {
+        // Lit pixels are black
+        Some(Image::Bitmap(multiple_enhancement_steps(
+            parameters.get("steps") as i32,
+            image,
+            enhancements,
+        )))
+    }
+
+    fn frames(
+        (enhancements, image): &(Vec<bool>, Grid<bool>),
+        _: Part,
+        parameters: &Parameters,
+        emit: &mut dyn FnMut(Frame),
+    ) {
+        enhancement_frames(image, enhancements, parameters.get("steps") as i32, emit);
+    }
+}
@@ -0,0 +1,233 @@
+use crate::generate::Rng;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Solution};
+use std::collections::HashMap;
+use std::hash::Hash;
+
+// Example starting positions from the puzzle description, used with --example
+const EXAMPLE: &str = "Player 1 starting position: 4
+Player 2 starting position: 8";
+
+fn parse_starting_position(line: &InputLine) -> Result<u64, ParseError> {
+    // Positions on the track range from 1 to 10
+    let (_, position) = line.split_once(line.text, ": ")?;
+    match line.parse::<u64>(position, "starting position")? {
+        position @ 1..=10 => Ok(position),
+        _ => Err(line.error(
+            position,
+            "Starting position has to be between 1 and 10, found",
+        )),
+    }
+}
+
+fn parse_input_data(input_data: &str) -> Result<(u64, u64), InvalidInput> {
+    let mut lines = parse::lines(Day21::DAY, input_data);
+    let mut next_position = || match lines.next() {
+        Some(line) => parse_starting_position(&line).map_err(InvalidInput::from),
+        None => Err(parse::end_of_input(Day21::DAY, input_data)
+            .error("", "Missing starting position")
+            .into()),
+    };
+    let first_position = next_position();
+    parse::combine(first_position, next_position())
+}
+
+#[derive(Debug, Clone)]
+struct DeterministicDice {
+    next_roll_result: u64,
+    number_of_rolls: u64,
+}
+
+impl DeterministicDice {
+    fn new() -> DeterministicDice {
+        DeterministicDice {
+            next_roll_result: 1,
+            number_of_rolls: 0,
+        }
+    }
+
+    fn roll_dice(&self) -> (u64, DeterministicDice) {
+        // Get sum of the three next deterministic dice rolls and update counters
+        (
+            (self.next_roll_result..(self.next_roll_result + 3)).sum(),
+            DeterministicDice {
+                next_roll_result: self.next_roll_result + 3,
+                number_of_rolls: self.number_of_rolls + 3,
+            },
+        )
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+struct Player {
+    points: u64,
+    position: u64,
+    has_active_turn: bool,
+}
+
+impl Player {
+    fn new(position: u64, has_active_turn: bool) -> Player {
+        Player {
+            points: 0,
+            position,
+            has_active_turn,
+        }
+    }
+
+    fn move_on_board(&self, rolls_sum: u64) -> Player {
+        // Update position and points with dice roll if it is the players turn
+        let next_position = if self.has_active_turn {
+            (self.position + rolls_sum - 1) % 10 + 1
+        } else {
+            self.position
+        };
+        let updated_points = if self.has_active_turn {
+            self.points + next_position
+        } else {
+            self.points
+        };
+        Player {
+            points: updated_points,
+            position: next_position,
+            has_active_turn: !self.has_active_turn,
+        }
+    }
+}
+
+fn play_game_with_deterministic_dice(
+    (starting_position_1, starting_position_2): &(u64, u64),
+    target_points: u64,
+) -> (Player, Player, DeterministicDice) {
+    // Play a single game until the target points with a deterministic dice
+    let mut player_1 = Player::new(*starting_position_1, true);
+    let mut player_2 = Player::new(*starting_position_2, false);
+    let mut dice = DeterministicDice::new();
+
+    while u64::max(player_1.points, player_2.points) < target_points {
+        let dice_roll = dice.roll_dice();
+        dice = dice_roll.1;
+        player_1 = player_1.move_on_board(dice_roll.0);
+        player_2 = player_2.move_on_board(dice_roll.0);
+    }
+
+    (player_1, player_2, dice)
+}
+
+fn play_game_with_dirac_dice(
+    (starting_position_1, starting_position_2): &(u64, u64),
+    target_points: u64,
+) -> (u64, u64) {
+    // Simulate all universes of the Dirac dice and count the total wins of each player
+    let player_1 = Player::new(*starting_position_1, true);
+    let player_2 = Player::new(*starting_position_2, false);
+
+    // Recursive solution to traverse the universe tree and create a memoization cache for already calculated sub trees
+    type WinsCache = HashMap<(Player, Player), (u64, u64)>;
+    // The cache is shared mutably by all recursion levels instead of being cloned for every sub tree
+    fn simulate_from_player_positions(
+        p_1: Player,
+        p_2: Player,
+        target_points: u64,
+        cache: &mut WinsCache,
+    ) -> (u64, u64) {
+        if let Some(wins) = cache.get(&(p_1.clone(), p_2.clone())) {
+            *wins
+        } else if p_1.points >= target_points {
+            (1, 0)
+        } else if p_2.points >= target_points {
+            (0, 1)
+        } else {
+            let mut player_1_wins = 0;
+            let mut player_2_wins = 0;
+            for dirac_dice_roll_1 in 1..=3 {
+                for dirac_dice_roll_2 in 1..=3 {
+                    for dirac_dice_roll_3 in 1..=3 {
+                        let dirac_dice_roll =
+                            dirac_dice_roll_1 + dirac_dice_roll_2 + dirac_dice_roll_3;
+                        let p_1_updated = p_1.move_on_board(dirac_dice_roll);
+                        let p_2_updated = p_2.move_on_board(dirac_dice_roll);
+                        let (p_1_w, p_2_w) = simulate_from_player_positions(
+                            p_1_updated,
+                            p_2_updated,
+                            target_points,
+                            cache,
+                        );
+                        player_1_wins += p_1_w;
+                        player_2_wins += p_2_w;
+                    }
+                }
+            }
+            cache.insert((p_1, p_2), (player_1_wins, player_2_wins));
+            (player_1_wins, player_2_wins)
+        }
+    }
+
+    let (player_1_wins, player_2_wins) =
+        simulate_from_player_positions(player_1, player_2, target_points, &mut HashMap::new());
+
+    (player_1_wins, player_2_wins)
+}
+
+fn generate_starting_positions(rng: &mut Rng, _size: usize) -> String {
+    // The board always has 10 positions, so there is nothing to scale
+    format!(
+        "Player 1 starting position: {}\nPlayer 2 starting position: {}\n",
+        rng.range(1..=10),
+        rng.range(1..=10)
+    )
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const DAY: u32 = 21;
+    const TITLE: &'static str = "Dirac Dice";
+    const EXAMPLE: &'static str = EXAMPLE;
+    // The number of universes won exceeds 64 bits beyond 27 points
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter {
+            name: "target",
+            description: "Points needed to win with the deterministic dice, part 1 only",
+            defaults: [1000, 1000],
+            minimum: 1,
+            maximum: 1_000_000,
+        },
+        Parameter {
+            name: "dirac-target",
+            description: "Points needed to win with the Dirac dice, part 2 only",
+            defaults: [21, 21],
+            minimum: 1,
+            maximum: 27,
+        },
+    ];
+
+    type Input = (u64, u64);
+
+    fn parse(input: &str) -> Result<(u64, u64), InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_starting_positions(rng, size)
+    }
+
+    fn part_one(
+        starting_positions: &(u64, u64),
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let (player_1, player_2, dice) =
+            play_game_with_deterministic_dice(starting_positions, parameters.get("target") as u64);
+        let loosing_score = u64::min(player_1.points, player_2.points);
+        Ok(Answer::new(loosing_score * dice.number_of_rolls))
+    }
+
+    fn part_two(
+        starting_positions: &(u64, u64),
+        parameters: &Parameters,
+    ) -> Result<Answer, String> {
+        let (player_1_wins, player_2_wins) =
+            play_game_with_dirac_dice(starting_positions, parameters.get("dirac-target") as u64);
+        Ok(Answer::new(u64::max(player_1_wins, player_2_wins)))
+    }
+}
@@ -0,0 +1,263 @@
+use crate::generate::Rng;
+use crate::geometry::Aabb;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Solution};
+
+// Example reboot steps from the puzzle description, used with --example
+const EXAMPLE: &str = "on x=-5..47,y=-31..22,z=-19..33
+on x=-44..5,y=-27..21,z=-14..35
+on x=-49..-1,y=-11..42,z=-10..38
+on x=-20..34,y=-40..6,z=-44..1
+off x=26..39,y=40..50,z=-2..11
+on x=-41..5,y=-41..6,z=-36..8
+off x=-43..-33,y=-45..-28,z=7..25
+on x=-33..15,y=-32..19,z=-34..11
+off x=35..47,y=-46..-34,z=-11..5
+on x=-14..36,y=-6..44,z=-16..29
+on x=-57795..-6158,y=29564..72030,z=20435..90618
+on x=36731..105352,y=-21140..28532,z=16094..90401
+on x=30999..107136,y=-53464..15513,z=8553..71215
+on x=13528..83982,y=-99403..-27377,z=-24141..23996
+on x=-72682..-12347,y=18159..111354,z=7391..80950
+on x=-1060..80757,y=-65301..-20884,z=-103788..-16709
+on x=-83015..-9461,y=-72160..-8347,z=-81239..-26856
+on x=-52752..22273,y=-49450..9096,z=54442..119054
+on x=-29982..40483,y=-108474..-28371,z=-24328..38471
+on x=-4958..62750,y=40422..118853,z=-7672..65583
+on x=55694..108686,y=-43367..46958,z=-26781..48729
+on x=-98497..-18186,y=-63569..3412,z=1232..88485
+on x=-726..56291,y=-62629..13224,z=18033..85226
+on x=-110886..-34664,y=-81338..-8658,z=8914..63723
+on x=-55829..24974,y=-16897..54165,z=-121762..-28058
+on x=-65152..-11147,y=22489..91432,z=-58782..1780
+on x=-120100..-32970,y=-46592..27473,z=-11695..61039
+on x=-18631..37533,y=-124565..-50804,z=-35667..28308
+on x=-57817..18248,y=49321..117703,z=5745..55881
+on x=14781..98692,y=-1341..70827,z=15753..70151
+on x=-34419..55919,y=-19626..40991,z=39015..114138
+on x=-60785..11593,y=-56135..2999,z=-95368..-26915
+on x=-32178..58085,y=17647..101866,z=-91405..-8878
+on x=-53655..12091,y=50097..105568,z=-75335..-4862
+on x=-111166..-40997,y=-71714..2688,z=5609..50954
+on x=-16602..70118,y=-98693..-44401,z=5197..76897
+on x=16383..101554,y=4615..83635,z=-44907..18747
+off x=-95822..-15171,y=-19987..48940,z=10804..104439
+on x=-89813..-14614,y=16069..88491,z=-3297..45228
+on x=41075..99376,y=-20427..49978,z=-52012..13762
+on x=-21330..50085,y=-17944..62733,z=-112280..-30197
+on x=-16478..35915,y=36008..118594,z=-7885..47086
+off x=-98156..-27851,y=-49952..43171,z=-99005..-8456
+off x=2032..69770,y=-71013..4824,z=7471..94418
+on x=43670..120875,y=-42068..12382,z=-24787..38892
+off x=37514..111226,y=-45862..25743,z=-16714..54663
+off x=25699..97951,y=-30668..59918,z=-15349..69697
+off x=-44271..17935,y=-9516..60759,z=49131..112598
+on x=-61695..-5813,y=40978..94975,z=8655..80240
+off x=-101086..-9439,y=-7088..67543,z=33935..83858
+off x=18020..114017,y=-48931..32606,z=21474..89843
+off x=-77139..10506,y=-89994..-18797,z=-80..59318
+off x=8476..79288,y=-75520..11602,z=-96624..-24783
+on x=-47488..-1262,y=24338..100707,z=16292..72967
+off x=-84341..13987,y=2429..92914,z=-90671..-1318
+off x=-37810..49457,y=-71013..-7894,z=-105357..-13188
+off x=-27365..46395,y=31009..98017,z=15428..76570
+off x=-70369..-16548,y=22648..78696,z=-1892..86821
+on x=-53470..21291,y=-120233..-33476,z=-44150..38147
+off x=-93533..-4276,y=-16170..68771,z=-104985..-24507";
+
+// Coordinates are limited, so the number of cubes in any cuboid of the reactor fits into an i64
+const MAX_COORDINATE: i64 = 1_000_000;
+
+fn parse_range(line: &InputLine, range: &str, axis: &str) -> Result<(i64, i64), InvalidInput> {
+    // Parse a range of the form axis=from..to
+    let (name, bounds) = line.split_once(range, "=")?;
+    if name != axis {
+        let message = format!("Expected the {} axis instead of", axis);
+        return Err(line.error(name, &message).into());
+    }
+    let (range_start, range_end) = line.split_once(bounds, "..")?;
+    let coordinate = |text: &str, description: &str| {
+        let value = line.parse::<i64>(text, description)?;
+        if value.abs() > MAX_COORDINATE {
+            let message = format!(
+                "Expected a coordinate from -{} to {} instead of",
+                MAX_COORDINATE, MAX_COORDINATE
+            );
+            return Err(line.error(text, &message));
+        }
+        Ok(value)
+    };
+    let (range_start, range_end) = parse::combine(
+        coordinate(range_start, "range start").map_err(InvalidInput::from),
+        coordinate(range_end, "range end").map_err(InvalidInput::from),
+    )?;
+    if range_start > range_end {
+        return Err(line
+            .error(bounds, "Range has to start at its lower end, found")
+            .into());
+    }
+    Ok((range_start, range_end))
+}
+
+fn parse_reboot_step(line: InputLine) -> Result<(bool, Aabb), InvalidInput> {
+    let (value, ranges) = line.split_once(line.text, " ")?;
+    let value = match value {
+        "on" => Ok(true),
+        "off" => Ok(false),
+        _ => Err(InvalidInput::from(
+            line.error(value, "Expected 'on' or 'off' instead of"),
+        )),
+    };
+    let ranges = ranges.split(',').collect::<Vec<&str>>();
+    let ranges = match ranges.len() {
+        3 => parse::collect_all(
+            ranges
+                .iter()
+                .zip(["x", "y", "z"])
+                .map(|(range, axis)| parse_range(&line, range, axis)),
+        ),
+        _ => Err(line
+            .error(line.text, "Expected x, y and z ranges in")
+            .into()),
+    };
+    let (value, ranges) = parse::combine(value, ranges)?;
+    let [x, y, z] = [ranges[0], ranges[1], ranges[2]];
+    Ok((value, Aabb::from_ranges(x.0..=x.1, y.0..=y.1, z.0..=z.1)))
+}
+
+fn parse_input_data(input_data: &str) -> Result<Vec<(bool, Aabb)>, InvalidInput> {
+    // Parse all initialization/reboot lines to check whether the cubes are meant to be on or off
+    // and in which cuboid the cubes are affected
+    parse::collect_all(parse::lines(Day22::DAY, input_data).map(parse_reboot_step))
+}
+
+pub fn lit_cubes_after_procedure_steps(
+    cuboid: &Aabb,
+    procedure_steps: &[(bool, Aabb)],
+) -> Option<i64> {
+    // Check how many cubes are lit after a sequence of initialization/reboot procedure steps
+    // None if a count exceeds the range of i64
+    match procedure_steps {
+        [] => Some(0), // No steps to execute => No lit cubes
+        [(value, procedure_cuboid)] => {
+            // A single step => Volume of the step cuboid, if the step is an 'on' step, 0 otherwise
+            match (*value, cuboid.intersection(procedure_cuboid)) {
+                (true, Some(intersection_cuboid)) => intersection_cuboid.volume(),
+                (_, None) | (false, Some(_)) => Some(0),
+            }
+        }
+        [previous_procedure_steps @ .., (current_procedure_step_value, current_procedure_step_cuboid)] =>
+        {
+            // At least two steps
+
+            // How many cubes were lit before the current step
+            let lit_reactor_cubes_before_current_step =
+                lit_cubes_after_procedure_steps(cuboid, previous_procedure_steps)?;
+
+            if let Some(intersection_cuboid) = cuboid.intersection(current_procedure_step_cuboid) {
+                // Calculate the lit cubes only inside of the intersection
+                let lit_cubes_in_intersection_before_current_step =
+                    lit_cubes_after_procedure_steps(
+                        &intersection_cuboid,
+                        previous_procedure_steps,
+                    )?;
+
+                // Current step is 'on'
+                //    -> Complete intersection + Lit cubes outside of intersection (all lit cubes before current step - lit cubes only in intersection before current step)
+                //       (Lit cubes inside of intersection have to be removed because they would be counted twice otherwise)
+                // Current step is 'off'
+                //    -> Lit cubes outside of intersection (all lit cubes before current step - lit cubes only in intersection before current step)
+                //       (Lit cubes inside of intersection are deactivated in this step and have to be remove from count)
+                lit_reactor_cubes_before_current_step
+                    .checked_sub(lit_cubes_in_intersection_before_current_step)?
+                    .checked_add(if *current_procedure_step_value {
+                        intersection_cuboid.volume()?
+                    } else {
+                        0
+                    })
+            } else {
+                // Cuboid of the current step is outside of the observed range does not influence the result
+                Some(lit_reactor_cubes_before_current_step)
+            }
+        }
+    }
+}
+
+fn generate_reboot_steps(rng: &mut Rng, size: usize) -> String {
+    // Like in the puzzle, the first 20 steps are inside of the initialization region
+    // and all further steps affect large cuboids anywhere in the reactor
+    (0..size)
+        .map(|step| {
+            let value = if step == 0 || rng.chance(0.7) {
+                "on"
+            } else {
+                "off"
+            };
+            let range = |rng: &mut Rng| {
+                if step < 20 {
+                    let start = rng.range(-50..=40);
+                    (start, i64::min(50, start + rng.range(10..=50)))
+                } else {
+                    let start = rng.range(-100_000..=80_000);
+                    (start, start + rng.range(10_000..=60_000))
+                }
+            };
+            let (x, y, z) = (range(rng), range(rng), range(rng));
+            format!(
+                "{} x={}..{},y={}..{},z={}..{}\n",
+                value, x.0, x.1, y.0, y.1, z.0, z.1
+            )
+        })
+        .collect()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u32 = 22;
+    const TITLE: &'static str = "Reactor Reboot";
+    const EXAMPLE: &'static str = EXAMPLE;
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "region",
+        description:
+            "Half the edge length of the initialization region around the origin, part 1 only",
+        defaults: [50, 50],
+        minimum: 0,
+        maximum: 1_000_000,
+    }];
+
+    type Input = Vec<(bool, Aabb)>;
+
+    fn parse(input: &str) -> Result<Vec<(bool, Aabb)>, InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_reboot_steps(rng, size)
+    }
+
+    fn part_one(procedure: &Vec<(bool, Aabb)>, parameters: &Parameters) -> Result<Answer, String> {
+        let region = parameters.get("region");
+        lit_cubes_after_procedure_steps(
+            &Aabb::from_ranges(-region..=region, -region..=region, -region..=region),
+            procedure.as_slice(),
+        )
+        .map(Answer::new)
+        .ok_or_else(|| String::from("The number of lit cubes exceeds the range of i64"))
+    }
+
+    fn part_two(procedure: &Vec<(bool, Aabb)>, _: &Parameters) -> Result<Answer, String> {
+        // The whole reactor is the bounding box of all procedure cuboids
+        let reactor = procedure
+            .iter()
+            .map(|(_, cuboid)| *cuboid)
+            .reduce(|reactor, cuboid| reactor.bounding_box(&cuboid));
+        reactor
+            .map_or(Some(0), |reactor| {
+                lit_cubes_after_procedure_steps(&reactor, procedure.as_slice())
+            })
+            .map(Answer::new)
+            .ok_or_else(|| String::from("The number of lit cubes exceeds the range of i64"))
+    }
+}
@@ -0,0 +1,508 @@
+use crate::frames::Frame;
+use crate::generate::Rng;
+use crate::info;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Part, Solution};
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt;
+
+// Example burrow from the puzzle description, used with --example
+const EXAMPLE: &str = "#############
+#...........#
+###B#C#B#D###
+  #A#D#C#A#
+  #########";
+
+const AMPHIPODS: [char; 4] = ['A', 'B', 'C', 'D'];
+const ENERGY: [u32; 4] = [1, 10, 100, 1000];
+const HALLWAY_LENGTH: usize = 11;
+// Hallway positions right above the rooms, amphipods never stop there
+const ROOM_ENTRANCES: [usize; 4] = [2, 4, 6, 8];
+// Part 2 unfolds the diagram, these rows are inserted between the first and the second room row
+const UNFOLDED_ROWS: [[usize; 4]; 2] = [[3, 2, 1, 0], [3, 1, 0, 2]];
+// A burrow state has 4 bits per cell, 32 cells fit the hallway and rooms up to 5 amphipods deep
+const MAX_DEPTH: usize = 5;
+const MAX_INPUT_ROWS: usize = MAX_DEPTH - UNFOLDED_ROWS.len();
+
+// Amphipod type per room, from the top row of the rooms to the bottom row
+pub type RoomRows = Vec<[usize; 4]>;
+
+// Positions of all amphipods packed into a single number, so states are cheap to copy, compare and hash
+// Cells are the hallway from left to right followed by the rooms from top to bottom,
+// each cell is 0 if empty or the amphipod type plus 1
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Burrow {
+    cells: u128,
+    depth: usize,
+}
+
+impl Burrow {
+    fn new(rows: &[[usize; 4]]) -> Burrow {
+        let mut burrow = Burrow {
+            cells: 0,
+            depth: rows.len(),
+        };
+        for (slot, row) in rows.iter().enumerate() {
+            for (room, amphipod) in row.iter().enumerate() {
+                burrow.set(burrow.room_cell(room, slot), Some(*amphipod));
+            }
+        }
+        burrow
+    }
+
+    fn get(&self, cell: usize) -> Option<usize> {
+        match (self.cells >> (4 * cell)) & 0xf {
+            0 => None,
+            value => Some(value as usize - 1),
+        }
+    }
+
+    fn set(&mut self, cell: usize, amphipod: Option<usize>) {
+        let value = amphipod.map_or(0, |amphipod| amphipod as u128 + 1);
+        self.cells = (self.cells & !(0xf << (4 * cell))) | (value << (4 * cell));
+    }
+
+    fn room_cell(&self, room: usize, slot: usize) -> usize {
+        HALLWAY_LENGTH + room * self.depth + slot
+    }
+
+    fn room(&self, room: usize) -> impl Iterator<Item = Option<usize>> + '_ {
+        (0..self.depth).map(move |slot| self.get(self.room_cell(room, slot)))
+    }
+
+    fn room_accepts(&self, room: usize) -> bool {
+        // Amphipods only enter their own room and only if no other type has to leave it
+        self.room(room)
+            .all(|amphipod| amphipod.is_none() || amphipod == Some(room))
+    }
+
+    fn is_organized(&self) -> bool {
+        (0..4).all(|room| self.room(room).all(|amphipod| amphipod == Some(room)))
+    }
+
+    fn hallway_is_free(&self, from: usize, to: usize) -> bool {
+        // All hallway cells between both positions are free, the start position itself is not checked
+        let cells = if from < to {
+            from + 1..=to
+        } else {
+            to..=from - 1
+        };
+        cells.into_iter().all(|x| self.get(x).is_none())
+    }
+
+    fn moved(&self, from: usize, to: usize) -> Burrow {
+        let mut burrow = *self;
+        burrow.set(to, self.get(from));
+        burrow.set(from, None);
+        burrow
+    }
+
+    fn moves(&self) -> Vec<(Burrow, u32)> {
+        // Amphipods move from a room into the hallway and from the hallway into their room
+        // A move from room to room costs the same as stopping in the hallway in between
+        let mut moves = Vec::new();
+        for x in 0..HALLWAY_LENGTH {
+            if let Some(amphipod) = self.get(x) {
+                let entrance = ROOM_ENTRANCES[amphipod];
+                if self.room_accepts(amphipod) && self.hallway_is_free(x, entrance) {
+                    // The amphipod enters the deepest free slot of its room
+                    let slot = self.room(amphipod).filter(Option::is_none).count() - 1;
+                    let steps = x.abs_diff(entrance) + slot + 1;
+                    moves.push((
+                        self.moved(x, self.room_cell(amphipod, slot)),
+                        steps as u32 * ENERGY[amphipod],
+                    ));
+                }
+            }
+        }
+        for (room, entrance) in ROOM_ENTRANCES.iter().cloned().enumerate() {
+            if self.room_accepts(room) {
+                continue;
+            }
+            let slot = self
+                .room(room)
+                .position(|amphipod| amphipod.is_some())
+                .unwrap();
+            let amphipod = self.get(self.room_cell(room, slot)).unwrap();
+            for x in (0..HALLWAY_LENGTH).filter(|x| !ROOM_ENTRANCES.contains(x)) {
+                if self.get(x).is_none() && self.hallway_is_free(entrance, x) {
+                    let steps = slot + 1 + x.abs_diff(entrance);
+                    moves.push((
+                        self.moved(self.room_cell(room, slot), x),
+                        steps as u32 * ENERGY[amphipod],
+                    ));
+                }
+            }
+        }
+        moves
+    }
+
+    fn reverse_moves(&self) -> Vec<Burrow> {
+        // Burrows from which a single move leads to this burrow, used to create burrows that can be organized
+        // Undoing an entering amphipod takes it from its room back to the hallway,
+        // undoing a leaving amphipod puts it on top of a room it could have left
+        let mut burrows = Vec::new();
+        for (room, entrance) in ROOM_ENTRANCES.iter().cloned().enumerate() {
+            let slot = match self.room(room).position(|amphipod| amphipod.is_some()) {
+                Some(slot) if self.room_accepts(room) => slot,
+                _ => continue,
+            };
+            for x in (0..HALLWAY_LENGTH).filter(|x| !ROOM_ENTRANCES.contains(x)) {
+                if self.get(x).is_none() && self.hallway_is_free(entrance, x) {
+                    burrows.push(self.moved(self.room_cell(room, slot), x));
+                }
+            }
+        }
+        for x in 0..HALLWAY_LENGTH {
+            if let Some(amphipod) = self.get(x) {
+                for (room, entrance) in ROOM_ENTRANCES.iter().cloned().enumerate() {
+                    let free_slots = self.room(room).filter(Option::is_none).count();
+                    if free_slots == 0 || !self.hallway_is_free(x, entrance) {
+                        continue;
+                    }
+                    let burrow = self.moved(x, self.room_cell(room, free_slots - 1));
+                    // The amphipod can only leave a room that holds another type
+                    if amphipod != room || !burrow.room_accepts(room) {
+                        burrows.push(burrow);
+                    }
+                }
+            }
+        }
+        burrows
+    }
+
+    fn rows(&self) -> RoomRows {
+        (0..self.depth)
+            .map(|slot| [0, 1, 2, 3].map(|room| self.get(self.room_cell(room, slot)).unwrap()))
+            .collect()
+    }
+
+    fn min_remaining_energy(&self) -> u32 {
+        // Energy to move every amphipod outside of its room to the top slot of its room, ignoring all others
+        // It never overestimates and decreases at most by the energy of a move, so A* finds the minimum
+        let hallway = (0..HALLWAY_LENGTH).filter_map(|x| {
+            self.get(x)
+                .map(|amphipod| (amphipod, x.abs_diff(ROOM_ENTRANCES[amphipod]) + 1))
+        });
+        let rooms = (0..4).flat_map(|room| {
+            self.room(room)
+                .enumerate()
+                .filter_map(move |(slot, amphipod)| match amphipod {
+                    Some(amphipod) if amphipod != room => Some((
+                        amphipod,
+                        slot + 1 + ROOM_ENTRANCES[room].abs_diff(ROOM_ENTRANCES[amphipod]) + 1,
+                    )),
+                    _ => None,
+                })
+        });
+        hallway
+            .chain(rooms)
+            .map(|(amphipod, steps)| steps as u32 * ENERGY[amphipod])
+            .sum()
+    }
+
+    fn describe_cell(&self, cell: usize) -> String {
+        if cell < HALLWAY_LENGTH {
+            format!("hallway {}", cell)
+        } else {
+            format!("room {}", AMPHIPODS[(cell - HALLWAY_LENGTH) / self.depth])
+        }
+    }
+}
+
+impl fmt::Display for Burrow {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Same diagram as the puzzle input
+        let cell = |cell: usize| self.get(cell).map_or('.', |amphipod| AMPHIPODS[amphipod]);
+        writeln!(f, "#############")?;
+        writeln!(f, "#{}#", (0..HALLWAY_LENGTH).map(cell).collect::<String>())?;
+        for slot in 0..self.depth {
+            let [a, b, c, d] = [0, 1, 2, 3].map(|room| cell(self.room_cell(room, slot)));
+            if slot == 0 {
+                writeln!(f, "###{}#{}#{}#{}###", a, b, c, d)?;
+            } else {
+                writeln!(f, "  #{}#{}#{}#{}#", a, b, c, d)?;
+            }
+        }
+        write!(f, "  #########")
+    }
+}
+
+// Struct for elements of the search priority queue of the A* algorithm
+// The queue is ordered by the energy spent so far plus the estimate of the remaining energy
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct QueueElement {
+    estimate: u32,
+    energy: u32,
+    burrow: Burrow,
+}
+
+impl Ord for QueueElement {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.estimate.cmp(&self.estimate)
+    }
+}
+
+impl PartialOrd for QueueElement {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn organize_amphipods(start: Burrow) -> Option<Vec<(Burrow, u32)>> {
+    // A* search for the least energy to organize the amphipods
+    // Returns all burrow states from the start to the organized burrow with the energy spent up to each state
+    let mut energies = HashMap::from([(start, (0, None))]);
+    let mut queue = BinaryHeap::from([QueueElement {
+        estimate: start.min_remaining_energy(),
+        energy: 0,
+        burrow: start,
+    }]);
+
+    while let Some(QueueElement { energy, burrow, .. }) = queue.pop() {
+        // Outdated queue elements of burrows that were reached with less energy in the meantime are skipped
+        if energies[&burrow].0 < energy {
+            continue;
+        }
+        if burrow.is_organized() {
+            info!("Searched {} burrow states", energies.len());
+            let mut path = vec![(burrow, energy)];
+            while let Some((_, Some(previous))) = energies.get(&path.last().unwrap().0) {
+                path.push((*previous, energies[previous].0));
+            }
+            path.reverse();
+            return Some(path);
+        }
+        for (next_burrow, move_energy) in burrow.moves() {
+            let next_energy = energy + move_energy;
+            if energies
+                .get(&next_burrow)
+                .is_none_or(|(known_energy, _)| next_energy < *known_energy)
+            {
+                energies.insert(next_burrow, (next_energy, Some(burrow)));
+                queue.push(QueueElement {
+                    estimate: next_energy + next_burrow.min_remaining_energy(),
+                    energy: next_energy,
+                    burrow: next_burrow,
+                });
+            }
+        }
+    }
+
+    None
+}
+
+fn describe_move(
+    (burrow, energy): &(Burrow, u32),
+    (next_burrow, next_energy): &(Burrow, u32),
+) -> String {
+    // A move empties one cell and fills another one
+    let cells = 0..HALLWAY_LENGTH + 4 * burrow.depth;
+    let from = cells
+        .clone()
+        .find(|cell| next_burrow.get(*cell).is_none() && burrow.get(*cell).is_some())
+        .unwrap();
+    let to = cells
+        .clone()
+        .find(|cell| burrow.get(*cell).is_none() && next_burrow.get(*cell).is_some())
+        .unwrap();
+    format!(
+        "{} from {} to {} using {} energy",
+        AMPHIPODS[burrow.get(from).unwrap()],
+        burrow.describe_cell(from),
+        burrow.describe_cell(to),
+        next_energy - energy
+    )
+}
+
+fn starting_burrow(rows: &RoomRows, part: Part) -> Burrow {
+    // The unfolded diagram of part 2 has two additional rows below the first row
+    match part {
+        Part::One => Burrow::new(rows),
+        Part::Two => {
+            let mut unfolded_rows = rows.clone();
+            unfolded_rows.splice(1..1, UNFOLDED_ROWS);
+            Burrow::new(&unfolded_rows)
+        }
+    }
+}
+
+fn solve_burrow(rows: &RoomRows, part: Part) -> Result<Answer, String> {
+    match organize_amphipods(starting_burrow(rows, part)) {
+        Some(path) => Ok(Answer::new(path.last().unwrap().1).with_extra(
+            "moves",
+            path.windows(2)
+                .map(|states| describe_move(&states[0], &states[1]))
+                .collect::<Vec<String>>(),
+        )),
+        None => Err(String::from("The amphipods can not be organized")),
+    }
+}
+
+fn parse_room_row(line: &InputLine, first_row: bool) -> Result<[usize; 4], InvalidInput> {
+    // Amphipods are in every second column, the first row is framed by the outer wall
+    let template = if first_row {
+        "###.#.#.#.###"
+    } else {
+        "  #.#.#.#.#"
+    };
+    let chars = line.text.trim_end().chars().collect::<Vec<char>>();
+    let walls_match = chars.len() == template.len()
+        && template
+            .chars()
+            .zip(chars.iter())
+            .all(|(expected, c)| expected == '.' || expected == *c);
+    if !walls_match {
+        let message = format!(
+            "Expected a room row like '{}' instead of",
+            template.replace('.', "A")
+        );
+        return Err(line.error(line.text, &message).into());
+    }
+    let amphipods = parse::collect_all([3, 5, 7, 9].map(|column| {
+        AMPHIPODS
+            .iter()
+            .position(|amphipod| *amphipod == chars[column])
+            .ok_or_else(|| line.error(&line.text[column..column + 1], "Invalid amphipod"))
+    }))?;
+    Ok([amphipods[0], amphipods[1], amphipods[2], amphipods[3]])
+}
+
+fn check_wall(
+    line: Option<&InputLine>,
+    input: &str,
+    wall: &str,
+    description: &str,
+) -> Result<(), InvalidInput> {
+    let message = format!("Expected the {} '{}' instead of", description, wall);
+    match line {
+        Some(line) if line.text.trim_end() == wall => Ok(()),
+        Some(line) => Err(line.error(line.text, &message).into()),
+        None => Err(parse::end_of_input(Day23::DAY, input)
+            .error("", &message)
+            .into()),
+    }
+}
+
+fn parse_burrow(input: &str) -> Result<RoomRows, InvalidInput> {
+    // The diagram has walls around an empty hallway and rooms of the same depth with amphipods of every type
+    let lines = parse::lines(Day23::DAY, input).collect::<Vec<InputLine>>();
+    let room_lines = match lines.len() {
+        0..=3 => &lines[lines.len()..],
+        length => &lines[2..length - 1],
+    };
+    let walls = parse::combine(
+        parse::combine(
+            check_wall(lines.first(), input, "#############", "top wall"),
+            check_wall(lines.get(1), input, "#...........#", "empty hallway"),
+        ),
+        check_wall(
+            lines.get(2 + room_lines.len()),
+            input,
+            "  #########",
+            "bottom wall",
+        ),
+    );
+    let rows = parse::collect_all(
+        room_lines
+            .iter()
+            .enumerate()
+            .map(|(i, line)| parse_room_row(line, i == 0)),
+    );
+    let (_, rows) = parse::combine(walls, rows)?;
+
+    let end_of_input = parse::end_of_input(Day23::DAY, input);
+    if !(1..=MAX_INPUT_ROWS).contains(&rows.len()) {
+        let message = format!(
+            "Expected 1 to {} room rows, found {}",
+            MAX_INPUT_ROWS,
+            rows.len()
+        );
+        return Err(end_of_input.error("", &message).into());
+    }
+    let violations = (0..4)
+        .filter_map(|amphipod| {
+            let count = rows.iter().flatten().filter(|a| **a == amphipod).count();
+            (count != rows.len()).then(|| {
+                let message = format!(
+                    "Expected {} amphipods of type {}, found {}",
+                    rows.len(),
+                    AMPHIPODS[amphipod],
+                    count
+                );
+                end_of_input.error("", &message)
+            })
+        })
+        .collect::<Vec<ParseError>>();
+    InvalidInput::from_violations(violations)?;
+    Ok(rows)
+}
+
+fn generate_burrow(rng: &mut Rng, size: usize) -> String {
+    // The size is the depth of the rooms, limited so the unfolded burrow of part 2 still fits into a state
+    // Random moves are undone starting at the organized burrow until the hallway is empty again,
+    // so the burrow can be organized, burrows whose unfolded variant can not be organized are dropped
+    // A walk that ends in a burrow no move leads to is started again
+    let depth = size.clamp(1, MAX_INPUT_ROWS);
+    let organized = Burrow::new(&vec![[0, 1, 2, 3]; depth]);
+    loop {
+        let mut burrow = organized;
+        for step in 0.. {
+            let hallway_is_empty = (0..HALLWAY_LENGTH).all(|x| burrow.get(x).is_none());
+            let reverse_moves = burrow.reverse_moves();
+            if step >= 8 * depth && hallway_is_empty || reverse_moves.is_empty() {
+                break;
+            }
+            burrow = *rng.choose(&reverse_moves);
+        }
+        let hallway_is_empty = (0..HALLWAY_LENGTH).all(|x| burrow.get(x).is_none());
+        if hallway_is_empty
+            && burrow != organized
+            && organize_amphipods(starting_burrow(&burrow.rows(), Part::Two)).is_some()
+        {
+            return format!("{}\n", burrow);
+        }
+    }
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const DAY: u32 = 23;
+    const TITLE: &'static str = "Amphipod";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = RoomRows;
+
+    fn parse(input: &str) -> Result<RoomRows, InvalidInput> {
+        parse_burrow(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_burrow(rng, size)
+    }
+
+    fn part_one(rows: &RoomRows, _: &Parameters) -> Result<Answer, String> {
+        solve_burrow(rows, Part::One)
+    }
+
+    fn part_two(rows: &RoomRows, _: &Parameters) -> Result<Answer, String> {
+        solve_burrow(rows, Part::Two)
+    }
+
+    fn frames(rows: &RoomRows, part: Part, _: &Parameters, emit: &mut dyn FnMut(Frame)) {
+        // The burrow after each move of the organization with the least energy
+        let path = organize_amphipods(starting_burrow(rows, part)).unwrap_or_default();
+        if let Some((start, _)) = path.first() {
+            emit(Frame::text("start", format!("{}\n", start)));
+        }
+        for states in path.windows(2) {
+            emit(Frame::text(
+                describe_move(&states[0], &states[1]),
+                format!("{}\n", states[1].0),
+            ));
+        }
+    }
+}
@@ -0,0 +1,510 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Solution};
+use crate::{debug, info};
+use std::fmt;
+
+// Shortened MONAD program with two digits instead of fourteen, used with --example
+// The puzzle description only has small programs without a model number check
+const EXAMPLE: &str = "inp w
+mul x 0
+add x z
+mod x 26
+div z 1
+add x 12
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 6
+mul y x
+add z y
+inp w
+mul x 0
+add x z
+mod x 26
+div z 26
+add x -11
+eql x w
+eql x 0
+mul y 0
+add y 25
+mul y x
+add y 1
+mul z y
+mul y 0
+add y w
+add y 3
+mul y x
+add z y";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    const NAMES: [&'static str; 4] = ["w", "x", "y", "z"];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", Register::NAMES[self.index()])
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Register(Register),
+    Number(i64),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Operand::Register(register) => write!(f, "{}", register),
+            Operand::Number(number) => write!(f, "{}", number),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Inp(Register),
+    Add(Register, Operand),
+    Mul(Register, Operand),
+    Div(Register, Operand),
+    Mod(Register, Operand),
+    Eql(Register, Operand),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Same notation as the puzzle input
+        match self {
+            Instruction::Inp(a) => write!(f, "inp {}", a),
+            Instruction::Add(a, b) => write!(f, "add {} {}", a, b),
+            Instruction::Mul(a, b) => write!(f, "mul {} {}", a, b),
+            Instruction::Div(a, b) => write!(f, "div {} {}", a, b),
+            Instruction::Mod(a, b) => write!(f, "mod {} {}", a, b),
+            Instruction::Eql(a, b) => write!(f, "eql {} {}", a, b),
+        }
+    }
+}
+
+// Reason an ALU program stopped, the instruction is counted from 1 like the lines of the program
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AluError {
+    MissingInput { instruction: usize },
+    DivisionByZero { instruction: usize },
+    InvalidModulo { instruction: usize },
+    Overflow { instruction: usize },
+}
+
+impl fmt::Display for AluError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AluError::MissingInput { instruction } => {
+                write!(
+                    f,
+                    "Instruction {} reads past the end of the inputs",
+                    instruction
+                )
+            }
+            AluError::DivisionByZero { instruction } => {
+                write!(f, "Instruction {} divides by zero", instruction)
+            }
+            AluError::InvalidModulo { instruction } => write!(
+                f,
+                "Instruction {} takes the modulo of a negative number or by a number below 1",
+                instruction
+            ),
+            AluError::Overflow { instruction } => {
+                write!(f, "Instruction {} overflows a register", instruction)
+            }
+        }
+    }
+}
+
+impl std::error::Error for AluError {}
+
+// Registers of the arithmetic logic unit, all start at 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Alu {
+    registers: [i64; 4],
+}
+
+impl Alu {
+    pub fn get(&self, register: Register) -> i64 {
+        self.registers[register.index()]
+    }
+
+    fn value(&self, operand: Operand) -> i64 {
+        match operand {
+            Operand::Register(register) => self.get(register),
+            Operand::Number(number) => number,
+        }
+    }
+
+    pub fn execute(
+        &mut self,
+        instruction: &Instruction,
+        inputs: &mut dyn Iterator<Item = i64>,
+        number: usize,
+    ) -> Result<(), AluError> {
+        // Execute a single instruction, the number of the instruction is only used for errors
+        let (a, result) = match *instruction {
+            Instruction::Inp(a) => (a, inputs.next()),
+            Instruction::Add(a, b) => (a, self.get(a).checked_add(self.value(b))),
+            Instruction::Mul(a, b) => (a, self.get(a).checked_mul(self.value(b))),
+            // Integer division of Rust truncates towards zero like the ALU
+            Instruction::Div(a, b) => (a, self.get(a).checked_div(self.value(b))),
+            Instruction::Mod(a, b) => match (self.get(a), self.value(b)) {
+                (a_value, b_value) if a_value >= 0 && b_value > 0 => (a, Some(a_value % b_value)),
+                _ => {
+                    return Err(AluError::InvalidModulo {
+                        instruction: number,
+                    })
+                }
+            },
+            Instruction::Eql(a, b) => (a, Some((self.get(a) == self.value(b)) as i64)),
+        };
+        // Without a result the instruction either ran out of inputs, divided by zero or overflowed
+        self.registers[a.index()] = result.ok_or(match instruction {
+            Instruction::Inp(_) => AluError::MissingInput {
+                instruction: number,
+            },
+            Instruction::Div(_, b) if self.value(*b) == 0 => AluError::DivisionByZero {
+                instruction: number,
+            },
+            _ => AluError::Overflow {
+                instruction: number,
+            },
+        })?;
+        Ok(())
+    }
+
+    pub fn run(program: &[Instruction], inputs: &[i64]) -> Result<Alu, AluError> {
+        // Run a whole program on a fresh ALU, each inp instruction reads the next input
+        let mut alu = Alu::default();
+        let mut inputs = inputs.iter().cloned();
+        for (i, instruction) in program.iter().enumerate() {
+            alu.execute(instruction, &mut inputs, i + 1)?;
+        }
+        Ok(alu)
+    }
+}
+
+fn parse_register(line: &InputLine, text: &str) -> Result<Register, ParseError> {
+    Register::NAMES
+        .iter()
+        .position(|name| *name == text)
+        .map(|i| [Register::W, Register::X, Register::Y, Register::Z][i])
+        .ok_or_else(|| line.error(text, "Expected register w, x, y or z instead of"))
+}
+
+fn parse_operand(line: &InputLine, text: &str) -> Result<Operand, ParseError> {
+    match parse_register(line, text) {
+        Ok(register) => Ok(Operand::Register(register)),
+        Err(_) => text
+            .parse::<i64>()
+            .map(Operand::Number)
+            .map_err(|_| line.error(text, "Expected a register or a number instead of")),
+    }
+}
+
+fn parse_instruction(line: InputLine) -> Result<Instruction, InvalidInput> {
+    let parts = line.text.split(' ').collect::<Vec<&str>>();
+    let operands = match parts[0] {
+        "inp" => 1,
+        "add" | "mul" | "div" | "mod" | "eql" => 2,
+        _ => {
+            return Err(line
+                .error(
+                    parts[0],
+                    "Expected inp, add, mul, div, mod or eql instead of",
+                )
+                .into())
+        }
+    };
+    if parts.len() != operands + 1 {
+        let message = format!("Expected {} operands for '{}' in", operands, parts[0]);
+        return Err(line.error(line.text, &message).into());
+    }
+    if operands == 1 {
+        return Ok(Instruction::Inp(parse_register(&line, parts[1])?));
+    }
+    let (a, b) = parse::combine(
+        parse_register(&line, parts[1]).map_err(InvalidInput::from),
+        parse_operand(&line, parts[2]).map_err(InvalidInput::from),
+    )?;
+    // Constant operands that always crash the ALU are rejected right away
+    match (parts[0], b) {
+        ("div" | "mod", Operand::Number(0)) => Err(line.error(parts[2], "Division by zero").into()),
+        ("mod", Operand::Number(number)) if number < 0 => {
+            Err(line.error(parts[2], "Modulo by a negative number").into())
+        }
+        ("add", _) => Ok(Instruction::Add(a, b)),
+        ("mul", _) => Ok(Instruction::Mul(a, b)),
+        ("div", _) => Ok(Instruction::Div(a, b)),
+        ("mod", _) => Ok(Instruction::Mod(a, b)),
+        _ => Ok(Instruction::Eql(a, b)),
+    }
+}
+
+fn parse_program(input: &str) -> Result<Vec<Instruction>, InvalidInput> {
+    parse::collect_all(parse::lines(Day24::DAY, input).map(parse_instruction))
+}
+
+// MONAD checks one digit per block of 18 instructions, only three numbers differ between the blocks
+// z is a stack of base 26 digits: blocks dividing z by 1 push their digit plus an offset,
+// blocks dividing z by 26 pop a value and only avoid pushing again if it matches their digit
+const MONAD_BLOCK: [&str; 18] = [
+    "inp w", "mul x 0", "add x z", "mod x 26", "div z ?", "add x ?", "eql x w", "eql x 0",
+    "mul y 0", "add y 25", "mul y x", "add y 1", "mul z y", "mul y 0", "add y w", "add y ?",
+    "mul y x", "add z y",
+];
+
+// Blocks push up to this many values, 26 to the power of it still fits into the registers
+const MAX_GENERATED_PAIRS: usize = 8;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Block {
+    divisor: i64,
+    x_offset: i64,
+    y_offset: i64,
+}
+
+// Digit of a popping block has to equal the digit of the matching pushing block plus the difference
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Constraint {
+    pub push: usize,
+    pub pop: usize,
+    pub difference: i64,
+}
+
+impl fmt::Display for Constraint {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Digits are counted from 1 like the digits of the model number
+        let sign = if self.difference < 0 { '-' } else { '+' };
+        write!(
+            f,
+            "digit {} = digit {} {} {}",
+            self.pop + 1,
+            self.push + 1,
+            sign,
+            self.difference.abs()
+        )
+    }
+}
+
+fn monad_blocks(program: &[Instruction]) -> Result<Vec<Block>, String> {
+    // Extract the numbers of each block, all other instructions have to match MONAD
+    if program.is_empty() || !program.len().is_multiple_of(MONAD_BLOCK.len()) {
+        return Err(format!(
+            "Expected blocks of {} instructions, found {} instructions",
+            MONAD_BLOCK.len(),
+            program.len()
+        ));
+    }
+    program
+        .chunks(MONAD_BLOCK.len())
+        .enumerate()
+        .map(|(i, block)| {
+            let mut numbers = Vec::new();
+            for (instruction, template) in block.iter().zip(MONAD_BLOCK) {
+                let text = instruction.to_string();
+                let matches = match template.strip_suffix('?') {
+                    Some(prefix) => match (text.strip_prefix(prefix), instruction) {
+                        (
+                            Some(_),
+                            Instruction::Add(_, Operand::Number(number))
+                            | Instruction::Div(_, Operand::Number(number)),
+                        ) => {
+                            numbers.push(*number);
+                            true
+                        }
+                        _ => false,
+                    },
+                    None => text == template,
+                };
+                if !matches {
+                    return Err(format!(
+                        "Expected '{}' in block {} instead of '{}'",
+                        template,
+                        i + 1,
+                        text
+                    ));
+                }
+            }
+            Ok(Block {
+                divisor: numbers[0],
+                x_offset: numbers[1],
+                y_offset: numbers[2],
+            })
+        })
+        .collect()
+}
+
+pub fn analyze_monad(program: &[Instruction]) -> Result<Vec<Constraint>, String> {
+    // Pair each popping block with the block that pushed the value it pops
+    // The program only ends with z = 0 if every pop matches, which ties the digits of each pair together
+    let mut stack: Vec<(usize, i64)> = Vec::new();
+    let mut constraints = Vec::new();
+    for (i, block) in monad_blocks(program)?.into_iter().enumerate() {
+        debug!(
+            "Block {}: div z {}, add x {}, add y {}",
+            i + 1,
+            block.divisor,
+            block.x_offset,
+            block.y_offset
+        );
+        match block.divisor {
+            // A digit can never match a value increased by more than 9, so these blocks always push
+            1 if block.x_offset > 9 => stack.push((i, block.y_offset)),
+            26 => match stack.pop() {
+                Some((push, y_offset)) => constraints.push(Constraint {
+                    push,
+                    pop: i,
+                    difference: y_offset.checked_add(block.x_offset).ok_or_else(|| {
+                        format!(
+                            "Block {} overflows the difference of add y {} and add x {}",
+                            i + 1,
+                            y_offset,
+                            block.x_offset
+                        )
+                    })?,
+                }),
+                None => return Err(format!("Block {} pops from an empty stack", i + 1)),
+            },
+            _ => {
+                return Err(format!(
+                    "Block {} neither always pushes nor pops, found div z {} and add x {}",
+                    i + 1,
+                    block.divisor,
+                    block.x_offset
+                ))
+            }
+        }
+    }
+    match stack.len() {
+        0 => Ok(constraints),
+        remaining => Err(format!(
+            "{} pushed values are never popped, so z can not end at 0",
+            remaining
+        )),
+    }
+}
+
+fn model_number(constraints: &[Constraint], digits: usize, largest: bool) -> Option<Vec<i64>> {
+    // The digits of each pair are chosen independently of the other pairs
+    let mut number = vec![0; digits];
+    for constraint in constraints {
+        let push = match (largest, constraint.difference) {
+            (_, difference) if difference.abs() > 8 => return None,
+            (true, difference) => 9.min(9 - difference),
+            (false, difference) => 1.max(1 - difference),
+        };
+        number[constraint.push] = push;
+        number[constraint.pop] = push + constraint.difference;
+    }
+    Some(number)
+}
+
+fn find_model_number(program: &[Instruction], largest: bool) -> Result<Answer, String> {
+    let constraints =
+        analyze_monad(program).map_err(|message| format!("Not a MONAD program: {}", message))?;
+    let digits = program.len() / MONAD_BLOCK.len();
+    let number = model_number(&constraints, digits, largest)
+        .ok_or_else(|| String::from("MONAD accepts no model number"))?;
+    // The interpreter cross-checks the analysis, the found number has to end with z = 0
+    let z = Alu::run(program, &number).map(|alu| alu.get(Register::Z));
+    info!("MONAD ends with z = {:?} for {:?}", z, number);
+    if z != Ok(0) {
+        return Err(String::from(
+            "MONAD rejects the model number of the analysis",
+        ));
+    }
+    Ok(
+        Answer::new(number.iter().map(i64::to_string).collect::<String>()).with_extra(
+            "constraints",
+            constraints
+                .iter()
+                .map(Constraint::to_string)
+                .collect::<Vec<String>>(),
+        ),
+    )
+}
+
+fn generate_monad(rng: &mut Rng, size: usize) -> String {
+    // The size is the number of digits, rounded down to pairs of a pushing and a popping block
+    let pairs = (size / 2).clamp(1, MAX_GENERATED_PAIRS);
+    // Random nesting of the pairs, a pop is only possible with a pushed value on the stack
+    let mut blocks = Vec::new();
+    let mut stack = Vec::new();
+    let mut remaining_pushes = pairs;
+    while remaining_pushes > 0 || !stack.is_empty() {
+        if remaining_pushes > 0 && (stack.is_empty() || rng.chance(0.5)) {
+            let y_offset = rng.range(1..=16);
+            stack.push(y_offset);
+            blocks.push((1, rng.range(10..=15), y_offset));
+            remaining_pushes -= 1;
+        } else {
+            // Differences stay within -8 to 8, so every generated program accepts model numbers
+            let y_offset = stack.pop().unwrap();
+            blocks.push((26, rng.range(-8..=8) - y_offset, rng.range(1..=16)));
+        }
+    }
+    let mut program = String::new();
+    for (divisor, x_offset, y_offset) in blocks {
+        let mut numbers = [divisor, x_offset, y_offset].into_iter();
+        for template in MONAD_BLOCK {
+            match template.strip_suffix('?') {
+                Some(prefix) => {
+                    program.push_str(&format!("{}{}\n", prefix, numbers.next().unwrap()))
+                }
+                None => program.push_str(&format!("{}\n", template)),
+            }
+        }
+    }
+    program
+}
+
+pub struct Day24;
+
+impl Solution for Day24 {
+    const DAY: u32 = 24;
+    const TITLE: &'static str = "Arithmetic Logic Unit";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &str) -> Result<Vec<Instruction>, InvalidInput> {
+        parse_program(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_monad(rng, size)
+    }
+
+    fn part_one(program: &Vec<Instruction>, _: &Parameters) -> Result<Answer, String> {
+        find_model_number(program, true)
+    }
+
+    fn part_two(program: &Vec<Instruction>, _: &Parameters) -> Result<Answer, String> {
+        find_model_number(program, false)
+    }
+}
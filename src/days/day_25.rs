@@ -0,0 +1,332 @@
+use crate::cycle::CycleDetector;
+use crate::frames::Frame;
+use crate::generate::{self, Rng};
+use crate::grid::Grid;
+use crate::json::Json;
+use crate::netpbm::{Image, Rgb};
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::InvalidInput;
+use crate::report::Report;
+use crate::solution::{Answer, Part, Solution};
+use crate::{debug, trace};
+use std::fmt;
+
+// Example sea floor from the puzzle description, used with --example
+const EXAMPLE: &str = "v...>>.vv>
+.vv>>.vv..
+>>.>v>...v
+>>v>>.>.v.
+v>v.vv.v..
+>.>>..v...
+.vv..>.>v.
+v.v..>>v.v
+....v..v.>";
+
+const EAST_COLOR: Rgb = Rgb(230, 120, 40);
+const SOUTH_COLOR: Rgb = Rgb(40, 90, 200);
+
+// Positions of both herds on a wrapping sea floor, one bit per cell and herd
+// Each row starts at a new word, so a whole row of a herd moves with a few word operations
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SeaFloor {
+    width: usize,
+    height: usize,
+    words_per_row: usize,
+    east: Vec<u64>,
+    south: Vec<u64>,
+}
+
+impl SeaFloor {
+    fn from_grid(cells: &Grid<char>) -> SeaFloor {
+        let width = cells.width();
+        let words_per_row = width.div_ceil(64);
+        let mut sea_floor = SeaFloor {
+            width,
+            height: cells.height(),
+            words_per_row,
+            east: vec![0; words_per_row * cells.height()],
+            south: vec![0; words_per_row * cells.height()],
+        };
+        for (x, y) in cells.positions() {
+            let (word, bit) = (y * words_per_row + x / 64, 1 << (x % 64));
+            match cells[(x, y)] {
+                '>' => sea_floor.east[word] |= bit,
+                'v' => sea_floor.south[word] |= bit,
+                _ => {}
+            }
+        }
+        sea_floor
+    }
+
+    fn cell(&self, (x, y): (usize, usize)) -> char {
+        let (word, bit) = (y * self.words_per_row + x / 64, 1 << (x % 64));
+        if self.east[word] & bit != 0 {
+            '>'
+        } else if self.south[word] & bit != 0 {
+            'v'
+        } else {
+            '.'
+        }
+    }
+
+    fn row<'a>(&self, herd: &'a [u64], y: usize) -> &'a [u64] {
+        &herd[y * self.words_per_row..(y + 1) * self.words_per_row]
+    }
+
+    fn rotate_east(&self, row: &[u64]) -> Vec<u64> {
+        // Every bit moves to the next higher position, the last cell of the row wraps around to the first
+        let last = self.width - 1;
+        let mut carry = (row[last / 64] >> (last % 64)) & 1;
+        let mut rotated = row
+            .iter()
+            .map(|word| {
+                let rotated_word = (word << 1) | carry;
+                carry = word >> 63;
+                rotated_word
+            })
+            .collect::<Vec<u64>>();
+        // The bit shifted past the last cell is not part of the row
+        rotated[last / 64] &= u64::MAX >> (63 - last % 64);
+        rotated
+    }
+
+    fn rotate_west(&self, row: &[u64]) -> Vec<u64> {
+        // Every bit moves to the next lower position, the first cell of the row wraps around to the last
+        let last = self.width - 1;
+        let mut rotated = (0..row.len())
+            .map(|i| (row[i] >> 1) | row.get(i + 1).map_or(0, |word| word << 63))
+            .collect::<Vec<u64>>();
+        rotated[last / 64] |= (row[0] & 1) << (last % 64);
+        rotated
+    }
+
+    fn step(&mut self) -> u32 {
+        // The east-facing herd moves first, the south-facing herd sees the moved east-facing herd
+        // Returns the number of sea cucumbers that moved
+        let mut moved = 0;
+        for y in 0..self.height {
+            let east = self.row(&self.east, y);
+            let south = self.row(&self.south, y);
+            let free = east.iter().zip(south).map(|(east, south)| !(east | south));
+            let targets = self
+                .rotate_east(east)
+                .iter()
+                .zip(free)
+                .map(|(moving, free)| moving & free)
+                .collect::<Vec<u64>>();
+            let movers = self.rotate_west(&targets);
+            let start = y * self.words_per_row;
+            for (i, (target, mover)) in targets.iter().zip(movers).enumerate() {
+                self.east[start + i] = (self.east[start + i] & !mover) | target;
+                moved += target.count_ones();
+            }
+        }
+        // All rows move at once, a sea cucumber that just moved into a row does not move again
+        let targets = (0..self.height)
+            .flat_map(|y| {
+                let below = (y + 1) % self.height;
+                let south = self.row(&self.south, y);
+                let east_below = self.row(&self.east, below);
+                let south_below = self.row(&self.south, below);
+                (0..self.words_per_row).map(move |i| south[i] & !(east_below[i] | south_below[i]))
+            })
+            .collect::<Vec<u64>>();
+        for y in 0..self.height {
+            let above = (y + self.height - 1) % self.height;
+            for i in 0..self.words_per_row {
+                let word = y * self.words_per_row + i;
+                self.south[word] =
+                    (self.south[word] & !targets[word]) | targets[above * self.words_per_row + i];
+                moved += targets[word].count_ones();
+            }
+        }
+        moved
+    }
+
+    fn image(&self) -> Image {
+        // East-facing sea cucumbers are orange, south-facing ones blue and the empty sea floor white
+        Image::Pixmap(Grid::from_fn(
+            self.width,
+            self.height,
+            |position| match self.cell(position) {
+                '>' => EAST_COLOR,
+                'v' => SOUTH_COLOR,
+                _ => Rgb::WHITE,
+            },
+        ))
+    }
+}
+
+impl fmt::Display for SeaFloor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Same notation as the puzzle input
+        for y in 0..self.height {
+            let row = (0..self.width)
+                .map(|x| self.cell((x, y)))
+                .collect::<String>();
+            writeln!(f, "{}", row)?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Outcome {
+    // First step in which no sea cucumber moves
+    Stopped(usize),
+    // Herds that keep moving repeat the same states
+    Cycle(usize),
+    StepLimit,
+}
+
+fn simulate(
+    sea_floor: &SeaFloor,
+    max_steps: usize,
+    mut visit: impl FnMut(usize, &SeaFloor),
+) -> Outcome {
+    // Simulate until no sea cucumber moves, the herds repeat themselves or the step limit is reached
+    let mut sea_floor = sea_floor.clone();
+    let mut cycle_detector = CycleDetector::new(&sea_floor);
+    visit(0, &sea_floor);
+    for step in 1..=max_steps {
+        let moved = sea_floor.step();
+        debug!("{} sea cucumbers move in step {}", moved, step);
+        trace!("Sea floor after step {}:\n{}", step, sea_floor);
+        visit(step, &sea_floor);
+        if moved == 0 {
+            return Outcome::Stopped(step);
+        }
+        if let Some(length) = cycle_detector.cycle_length(&sea_floor) {
+            return Outcome::Cycle(length);
+        }
+    }
+    Outcome::StepLimit
+}
+
+fn sea_floor_at_step(sea_floor: &SeaFloor, step: usize) -> (usize, SeaFloor) {
+    // The state after the step, or the final state and its step if the herds stop earlier
+    // Herds that repeat their positions are simulated up to the step
+    let mut sea_floor = sea_floor.clone();
+    for current_step in 1..=step {
+        if sea_floor.step() == 0 {
+            return (current_step, sea_floor);
+        }
+    }
+    (step, sea_floor)
+}
+
+fn first_step_without_movement(sea_floor: &SeaFloor) -> Result<Answer, String> {
+    match simulate(sea_floor, usize::MAX, |_, _| {}) {
+        Outcome::Stopped(step) => Ok(Answer::new(step)),
+        Outcome::Cycle(length) => Err(format!(
+            "The sea cucumbers never stop, their positions repeat every {} steps",
+            length
+        )),
+        Outcome::StepLimit => unreachable!("The herds either stop or repeat their positions"),
+    }
+}
+
+fn generate_sea_floor(rng: &mut Rng, size: usize) -> String {
+    // Random herds on a square sea floor, the size is its width and height
+    // Sparse herds can move forever, grids are retried with more sea cucumbers until they jam
+    for empty_percent in (10..=50).rev().step_by(10) {
+        for _ in 0..3 {
+            let text = generate::grid(rng, size, size, |rng| match rng.below(100) {
+                n if n < empty_percent => '.',
+                n if n % 2 == 0 => '>',
+                _ => 'v',
+            });
+            let sea_floor = SeaFloor::from_grid(&parse_sea_floor(&text).unwrap());
+            if let Outcome::Stopped(_) = simulate(&sea_floor, usize::MAX, |_, _| {}) {
+                return text;
+            }
+        }
+    }
+    // Completely jammed herds, every row and column is full
+    generate::grid(rng, size, size, |rng| *rng.choose(&['>', 'v']))
+}
+
+fn parse_sea_floor(input: &str) -> Result<Grid<char>, InvalidInput> {
+    Grid::parse_chars(Day25::DAY, input, "sea floor cell", |c| {
+        ['>', 'v', '.'].contains(&c).then_some(c)
+    })
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u32 = 25;
+    const TITLE: &'static str = "Sea Cucumber";
+    const EXAMPLE: &'static str = EXAMPLE;
+    // The last star of the calendar is given for all other stars
+    const PARTS: &'static [Part] = &[Part::One];
+    const PARAMETERS: &'static [Parameter] = &[
+        Parameter {
+            name: "step",
+            description:
+                "Step of the sea floor in the report, image and frames, capped at the last step",
+            defaults: [1_000_000, 1_000_000],
+            minimum: 0,
+            maximum: 1_000_000,
+        },
+        Parameter {
+            name: "show",
+            description: "1 reports the sea floor at the step below the answer, 0 skips it",
+            defaults: [0, 0],
+            minimum: 0,
+            maximum: 1,
+        },
+    ];
+
+    type Input = SeaFloor;
+
+    fn parse(input: &str) -> Result<SeaFloor, InvalidInput> {
+        parse_sea_floor(input).map(|cells| SeaFloor::from_grid(&cells))
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_sea_floor(rng, size)
+    }
+
+    fn part_one(sea_floor: &SeaFloor, _: &Parameters) -> Result<Answer, String> {
+        first_step_without_movement(sea_floor)
+    }
+
+    fn part_two(_: &SeaFloor, _: &Parameters) -> Result<Answer, String> {
+        // Not in PARTS, the runner skips it
+        Err(String::from("Day 25 has no second puzzle"))
+    }
+
+    fn report(sea_floor: &SeaFloor, _: Part, parameters: &Parameters) -> Option<Report> {
+        // The sea floor is only printed when asked for, large grids would bury the answer
+        if parameters.get("show") == 0 {
+            return None;
+        }
+        let (step, shown) = sea_floor_at_step(sea_floor, parameters.get("step") as usize);
+        let text = shown.to_string();
+        Some(Report {
+            title: format!("Sea floor after step {}", step),
+            data: Json::object()
+                .with("step", step)
+                .with("sea_floor", text.clone()),
+            text,
+        })
+    }
+
+    fn image(sea_floor: &SeaFloor, _: Part, parameters: &Parameters) -> Option<Image> {
+        Some(
+            sea_floor_at_step(sea_floor, parameters.get("step") as usize)
+                .1
+                .image(),
+        )
+    }
+
+    fn frames(sea_floor: &SeaFloor, _: Part, parameters: &Parameters, emit: &mut dyn FnMut(Frame)) {
+        // The sea floor after each step up to the selected step
+        simulate(
+            sea_floor,
+            parameters.get("step") as usize,
+            |step, sea_floor| emit(Frame::image(format!("step {}", step), sea_floor.image())),
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use crate::generate::{self, Rng};
+use crate::parameters::Parameters;
+use crate::parse::{self, InvalidInput};
+use crate::solution::{Answer, Solution};
+
+// Example diagnostic report from the puzzle description, used with --example
+const EXAMPLE: &str = "00100
+11110
+10110
+10111
+10101
+01111
+00111
+11100
+10000
+11001
+00010
+01010";
+
+fn count_bits_in_measurement_column(column_index: usize, measurements: &[&str]) -> (usize, usize) {
+    // Count the occurrences of zeros and ones in a given column of the measurements
+    let column_string = measurements
+        .iter()
+        .map(move |measurement_string| measurement_string.chars().nth(column_index).unwrap_or('-'))
+        .collect::<String>();
+    (
+        column_string.matches('0').count(),
+        column_string.matches('1').count(),
+    )
+}
+
+fn calculate_gamma_epsilon_diagnostics(measurements: &[&str]) -> (i32, i32) {
+    let line_length = measurements[0].len();
+    (0..line_length)
+        .map(|index| count_bits_in_measurement_column(index, measurements))
+        .rev()
+        .enumerate()
+        .fold((0, 0), |(gamma, epsilon), (index, (zeros, ones))| {
+            if zeros > ones {
+                (gamma, epsilon + 2i32.pow(index as u32))
+            } else {
+                (gamma + 2i32.pow(index as u32), epsilon)
+            }
+        })
+}
+
+fn calculate_air_diagnostics(measurements: &[&str], is_oxygen: bool) -> i32 {
+    let line_length = measurements[0].len();
+    let oxygen_measurement =
+        (0..line_length).fold(measurements.to_vec(), |remaining_measurements, index| {
+            if remaining_measurements.len() == 1 {
+                remaining_measurements
+            } else {
+                let (zeros, ones) =
+                    count_bits_in_measurement_column(index, &remaining_measurements);
+                let filter_bit = match (is_oxygen, zeros, ones) {
+                    (true, zeros, ones) if zeros > ones => '0',
+                    (true, _, _) => '1',
+                    // A bit value without any measurements would remove all measurements
+                    (false, zeros, ones) if zeros > ones && ones > 0 => '1',
+                    (false, 0, _) => '1',
+                    (false, _, _) => '0',
+                };
+                remaining_measurements
+                    .iter()
+                    .cloned()
+                    .filter(|measurement_string| {
+                        measurement_string.chars().nth(index).unwrap_or('-') == filter_bit
+                    })
+                    .collect()
+            }
+        })[0];
+    i32::from_str_radix(oxygen_measurement, 2).unwrap()
+}
+
+fn parse_measurements(input: &str) -> Result<Vec<String>, InvalidInput> {
+    // All measurements have to be binary numbers of the same length
+    // The diagnostics are i32 values, so a measurement has between 1 and 31 bits
+    let lines = parse::lines(Day3::DAY, input).collect::<Vec<_>>();
+    let line_length = match lines.first() {
+        Some(line) if line.text.is_empty() || line.text.len() > 31 => {
+            return Err(line
+                .error(
+                    line.text,
+                    "Expected a measurement with 1 to 31 bits instead of",
+                )
+                .into())
+        }
+        Some(line) => line.text.len(),
+        None => {
+            return Err(parse::end_of_input(Day3::DAY, input)
+                .error("", "Missing measurements")
+                .into())
+        }
+    };
+    let mut violations = Vec::new();
+    for line in lines.iter() {
+        violations.extend(
+            line.text
+                .chars()
+                .enumerate()
+                .filter_map(|(index, c)| line.digit(index, c, 2).err()),
+        );
+        if line.text.len() != line_length {
+            violations.push(line.error(
+                line.text,
+                &format!(
+                    "Expected a measurement with {} bits instead of",
+                    line_length
+                ),
+            ));
+        }
+    }
+    InvalidInput::from_violations(violations)?;
+    Ok(lines.iter().map(|line| String::from(line.text)).collect())
+}
+
+fn generate_measurements(rng: &mut Rng, size: usize) -> String {
+    // Random 12 bit measurements
+    generate::grid(rng, 12, size, |rng| generate::digit(rng, 0..=1))
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u32 = 3;
+    const TITLE: &'static str = "Binary Diagnostic";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &str) -> Result<Vec<String>, InvalidInput> {
+        parse_measurements(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_measurements(rng, size)
+    }
+
+    fn part_one(measurements: &Vec<String>, _: &Parameters) -> Result<Answer, String> {
+        let measurements = measurements
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+        let (gamma, epsilon) = calculate_gamma_epsilon_diagnostics(&measurements);
+        Ok(Answer::new(gamma as i64 * epsilon as i64))
+    }
+
+    fn part_two(measurements: &Vec<String>, _: &Parameters) -> Result<Answer, String> {
+        let measurements = measurements
+            .iter()
+            .map(String::as_str)
+            .collect::<Vec<&str>>();
+        let oxygen = calculate_air_diagnostics(&measurements, true);
+        let co2 = calculate_air_diagnostics(&measurements, false);
+        Ok(Answer::new(oxygen as i64 * co2 as i64))
+    }
+}
@@ -0,0 +1,227 @@
+use crate::debug;
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Solution};
+
+// Example bingo subsystem data from the puzzle description, used with --example
+const EXAMPLE: &str = "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+ 8  2 23  4 24
+21  9 14 16  7
+ 6 10  3 18  5
+ 1 12 20 15 19
+
+ 3 15  0  2 22
+ 9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+ 2  0 12  3  7";
+
+#[derive(Clone)]
+pub struct BingoCard {
+    numbers: [[i32; 5]; 5],
+    hits_per_row: [i32; 5],
+    hits_per_column: [i32; 5],
+    bingo: bool,
+}
+
+impl BingoCard {
+    fn new(number_rows: &[InputLine]) -> Result<BingoCard, InvalidInput> {
+        // A card is separated by an empty line and consists of 5 rows with 5 numbers each
+        match number_rows {
+            [separator, ..] if !separator.text.trim().is_empty() => {
+                return Err(separator
+                    .error(separator.text, "Expected an empty line instead of")
+                    .into())
+            }
+            [separator, ..] if number_rows.len() < 6 => {
+                return Err(separator
+                    .end_error("Expected 5 rows for the bingo card")
+                    .into())
+            }
+            _ => {}
+        }
+        let mut numbers = [[0; 5]; 5];
+        let mut violations = Vec::new();
+        for (i, row) in number_rows[1..=5].iter().enumerate() {
+            let row_numbers = row
+                .text
+                .split(' ')
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<&str>>();
+            if row_numbers.len() != 5 {
+                violations.push(row.error(row.text, "Expected 5 numbers in bingo card row"));
+                continue;
+            }
+            for (j, number) in row_numbers.into_iter().enumerate() {
+                match row.parse(number, "bingo number") {
+                    Ok(number) => numbers[i][j] = number,
+                    Err(error) => violations.push(error),
+                }
+            }
+        }
+        InvalidInput::from_violations(violations)?;
+        Ok(BingoCard {
+            numbers,
+            hits_per_row: [0; 5],
+            hits_per_column: [0; 5],
+            bingo: false,
+        })
+    }
+
+    fn sum_fields(&self) -> i32 {
+        let mut sum = 0;
+        for row in 0..5 {
+            for column in 0..5 {
+                if self.numbers[row][column] != -1 {
+                    sum += self.numbers[row][column];
+                }
+            }
+        }
+        sum
+    }
+
+    fn check_bingo(&mut self, number: i32) -> Option<i32> {
+        if !self.bingo {
+            let mut hit = (9, 9);
+            // Search for the drawn number in the card
+            for row in 0..5 {
+                for column in 0..5 {
+                    if self.numbers[row][column] == number {
+                        hit = (row, column);
+                        self.numbers[row][column] = -1;
+                    }
+                }
+            }
+            // If the card contains the drawn number, check if it is a bingo
+            let (hit_row, hit_column) = hit;
+            if hit_row != 9 && hit_column != 9 {
+                self.hits_per_row[hit_row] += 1;
+                self.hits_per_column[hit_column] += 1;
+                if self.hits_per_row[hit_row] == 5 || self.hits_per_column[hit_column] == 5 {
+                    self.bingo = true;
+                    return Some(self.sum_fields() * number);
+                }
+            }
+        }
+        None
+    }
+}
+
+fn parse_bingo_cards(input: &str) -> Result<(Vec<i32>, Vec<BingoCard>), InvalidInput> {
+    // Split input lines into sequence of drawn numbers and the bingo cards
+    let lines = parse::lines(Day4::DAY, input).collect::<Vec<InputLine>>();
+    let first_line = match lines.first() {
+        Some(line) => line,
+        None => {
+            return Err(parse::end_of_input(Day4::DAY, input)
+                .error("", "Missing drawn numbers")
+                .into())
+        }
+    };
+    let drawn_numbers = parse::collect_all(
+        first_line
+            .text
+            .split(',')
+            .map(|s| first_line.parse(s, "drawn number")),
+    );
+    let cards = parse::collect_all(lines[1..lines.len()].chunks(6).map(BingoCard::new));
+
+    parse::combine(drawn_numbers, cards)
+}
+
+fn play_bingo(drawn_numbers: &[i32], bingo_cards: &[BingoCard]) -> (Option<i32>, Option<i32>) {
+    // Draw all numbers and remember the scores of the first and the last winning card
+    let mut bingo_cards = bingo_cards.to_vec();
+    let mut winning_score = None;
+    let mut last_score = None;
+    for number in drawn_numbers.iter().cloned() {
+        for (i, card) in bingo_cards.iter_mut().enumerate() {
+            let card_score = card.check_bingo(number);
+            if let Some(score) = card_score {
+                debug!(
+                    "Card {} wins after drawing {} with score {}",
+                    i + 1,
+                    number,
+                    score
+                );
+                if winning_score.is_none() {
+                    winning_score = card_score;
+                }
+                last_score = card_score;
+            }
+        }
+    }
+    (winning_score, last_score)
+}
+
+fn generate_bingo_subsystem(rng: &mut Rng, size: usize) -> String {
+    // All numbers from 0 to 99 are drawn in random order, so every card wins eventually
+    let mut numbers = (0..100).collect::<Vec<i32>>();
+    rng.shuffle(&mut numbers);
+    let drawn_numbers = numbers
+        .iter()
+        .map(i32::to_string)
+        .collect::<Vec<String>>()
+        .join(",");
+    let mut text = format!("{}\n", drawn_numbers);
+    for _ in 0..size {
+        // Each card has 25 distinct numbers
+        rng.shuffle(&mut numbers);
+        text.push('\n');
+        for row in numbers[..25].chunks(5) {
+            let row = row
+                .iter()
+                .map(|number| format!("{:>2}", number))
+                .collect::<Vec<String>>();
+            text.push_str(&format!("{}\n", row.join(" ")));
+        }
+    }
+    text
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u32 = 4;
+    const TITLE: &'static str = "Giant Squid";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = (Vec<i32>, Vec<BingoCard>);
+
+    fn parse(input: &str) -> Result<(Vec<i32>, Vec<BingoCard>), InvalidInput> {
+        parse_bingo_cards(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_bingo_subsystem(rng, size)
+    }
+
+    fn part_one(
+        (drawn_numbers, bingo_cards): &(Vec<i32>, Vec<BingoCard>),
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        match play_bingo(drawn_numbers, bingo_cards) {
+            (Some(winning_card_score), _) => Ok(Answer::new(winning_card_score)),
+            _ => Err(String::from("No card has won")),
+        }
+    }
+
+    fn part_two(
+        (drawn_numbers, bingo_cards): &(Vec<i32>, Vec<BingoCard>),
+        _: &Parameters,
+    ) -> Result<Answer, String> {
+        match play_bingo(drawn_numbers, bingo_cards) {
+            (_, Some(last_card_score)) => Ok(Answer::new(last_card_score)),
+            _ => Err(String::from("No card has won")),
+        }
+    }
+}
@@ -0,0 +1,211 @@
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::netpbm::Image;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput, ParseError};
+use crate::solution::{Answer, Part, Solution};
+use std::cmp;
+
+// Start and end point of a vent line
+pub type Line = ((usize, usize), (usize, usize));
+
+// Highest coordinate of a vent line, the puzzle input stays below 1000
+const MAX_COORDINATE: usize = 4095;
+
+// Example hydrothermal vent lines from the puzzle description, used with --example
+const EXAMPLE: &str = "0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2";
+
+fn parse_coordinate(line: &InputLine, text: &str) -> Result<usize, ParseError> {
+    // The map holds a counter for every point up to the highest coordinate
+    match line.parse(text, "coordinate")? {
+        coordinate if coordinate <= MAX_COORDINATE => Ok(coordinate),
+        _ => Err(line.error(
+            text,
+            &format!("Coordinate has to be at most {}, found", MAX_COORDINATE),
+        )),
+    }
+}
+
+fn parse_point(line: &InputLine, text: &str) -> Result<(usize, usize), InvalidInput> {
+    let (x, y) = line.split_once(text, ",")?;
+    parse::combine(
+        parse_coordinate(line, x).map_err(InvalidInput::from),
+        parse_coordinate(line, y).map_err(InvalidInput::from),
+    )
+}
+
+fn parse_vent_line(line: InputLine) -> Result<Line, InvalidInput> {
+    // Vent lines are horizontal, vertical or diagonal at exactly 45 degrees
+    let (line_start, line_end) = line.split_once(line.text, " -> ")?;
+    let (start, end) =
+        parse::combine(parse_point(&line, line_start), parse_point(&line, line_end))?;
+    if start.0 != end.0 && start.1 != end.1 && start.0.abs_diff(end.0) != start.1.abs_diff(end.1) {
+        return Err(line
+            .error(
+                line.text,
+                "Expected a horizontal, vertical or diagonal line instead of",
+            )
+            .into());
+    }
+    Ok((start, end))
+}
+
+fn parse_line_coordinates(line_coordinates: &str) -> Result<Vec<Line>, InvalidInput> {
+    // Gen numeric coordinates from textual representation
+    parse::collect_all(parse::lines(Day5::DAY, line_coordinates).map(parse_vent_line))
+}
+
+fn get_dimensions(line_coordinates: &[Line]) -> (usize, usize) {
+    // Get highest coordinates for the dimensions of the map
+    line_coordinates.iter().fold(
+        (0, 0),
+        |(max_x, max_y), ((start_x, start_y), (end_x, end_y))| {
+            (
+                cmp::max(max_x, cmp::max(*start_x, *end_x)),
+                cmp::max(max_y, cmp::max(*start_y, *end_y)),
+            )
+        },
+    )
+}
+
+fn get_points_of_line(start_x: f64, start_y: f64, end_x: f64, end_y: f64) -> Vec<(usize, usize)> {
+    // Generate the discrete points of the whole line from the coordinates
+    let mut points = Vec::new();
+    let length = cmp::max(
+        (end_x - start_x).abs() as i32,
+        (end_y - start_y).abs() as i32,
+    );
+    let (x_d, y_d) = (
+        (end_x - start_x).clamp(-1.0, 1.0),
+        (end_y - start_y).clamp(-1.0, 1.0),
+    );
+    let mut x = start_x;
+    let mut y = start_y;
+    for _ in 0..=length {
+        points.push((x as usize, y as usize));
+        x += x_d;
+        y += y_d;
+    }
+    points
+}
+
+fn fill_map(
+    line_coordinates: &[Line],
+    dimensions: &(usize, usize),
+    draw_diagonal_lines: bool,
+) -> Vec<Vec<usize>> {
+    // Draw all lines on the map
+    let mut map = vec![vec![0; dimensions.0 + 1]; dimensions.1 + 1];
+    for ((start_x, start_y), (end_x, end_y)) in line_coordinates {
+        if draw_diagonal_lines || start_x == end_x || start_y == end_y {
+            let line_points = get_points_of_line(
+                *start_x as f64,
+                *start_y as f64,
+                *end_x as f64,
+                *end_y as f64,
+            );
+            for (x, y) in line_points {
+                map[y][x] += 1;
+            }
+        }
+    }
+    map
+}
+
+fn count_dangerous_areas(map: Vec<Vec<usize>>) -> i32 {
+    // Count dangerous areas of the map (overlapping lines)
+    let mut areas = 0;
+    for row in map {
+        for overlaps in row {
+            if overlaps > 1 {
+                areas += 1;
+            }
+        }
+    }
+    areas
+}
+
+fn generate_vent_lines(rng: &mut Rng, size: usize) -> String {
+    // Horizontal, vertical and diagonal lines with coordinates below 1000
+    let directions = [
+        (1, 0),
+        (-1, 0),
+        (0, 1),
+        (0, -1),
+        (1, 1),
+        (1, -1),
+        (-1, 1),
+        (-1, -1),
+    ];
+    (0..size)
+        .map(|_| {
+            let (start_x, start_y) = (rng.range(0..=999), rng.range(0..=999));
+            let (d_x, d_y) = *rng.choose(&directions);
+            // Longest line in this direction that stays inside of the coordinate range
+            let max_length = [(start_x, d_x), (start_y, d_y)]
+                .iter()
+                .map(|(start, d)| match d {
+                    1 => 999 - start,
+                    -1 => *start,
+                    _ => 999,
+                })
+                .min()
+                .unwrap();
+            let length = rng.range(0..=max_length);
+            format!(
+                "{},{} -> {},{}\n",
+                start_x,
+                start_y,
+                start_x + d_x * length,
+                start_y + d_y * length
+            )
+        })
+        .collect()
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u32 = 5;
+    const TITLE: &'static str = "Hydrothermal Venture";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<Line>;
+
+    fn parse(input: &str) -> Result<Vec<Line>, InvalidInput> {
+        parse_line_coordinates(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_vent_lines(rng, size)
+    }
+
+    fn part_one(coordinates: &Vec<Line>, _: &Parameters) -> Result<Answer, String> {
+        let dimensions = get_dimensions(coordinates);
+        let map = fill_map(coordinates, &dimensions, false);
+        Ok(Answer::new(count_dangerous_areas(map)))
+    }
+
+    fn part_two(coordinates: &Vec<Line>, _: &Parameters) -> Result<Answer, String> {
+        let dimensions = get_dimensions(coordinates);
+        let map = fill_map(coordinates, &dimensions, true);
+        Ok(Answer::new(count_dangerous_areas(map)))
+    }
+
+    fn image(coordinates: &Vec<Line>, part: Part, _: &Parameters) -> Option<Image> {
+        // Brightness shows the number of overlapping lines
+        let dimensions = get_dimensions(coordinates);
+        let map = fill_map(coordinates, &dimensions, part == Part::Two);
+        let overlaps = Grid::from_rows(map).unwrap();
+        Some(Image::graymap(&overlaps.map(|lines| *lines as u16)))
+    }
+}
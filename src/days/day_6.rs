@@ -0,0 +1,116 @@
+use crate::frames::Frame;
+use crate::generate::Rng;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::{self, InvalidInput};
+use crate::solution::{Answer, Part, Solution};
+
+// Example lanternfish timers from the puzzle description, used with --example
+const EXAMPLE: &str = "3,4,3,1,2";
+
+fn parse_input_data(input_data: &str) -> Result<[u128; 9], InvalidInput> {
+    let mut population = [0; 9];
+    // Get numeric representation from input string as amount of fish per timer state
+    let line = parse::single_line(Day6::DAY, input_data)?;
+    let timers = parse::collect_all(line.text.trim().split(',').map(|timer| {
+        match line.parse::<usize>(timer, "timer")? {
+            n if n < population.len() => Ok(n),
+            _ => Err(line.error(timer, "Timer has to be between 0 and 8, found")),
+        }
+    }))?;
+    for timer in timers {
+        population[timer] += 1;
+    }
+    Ok(population)
+}
+
+fn simulate_day(population: &mut [u128; 9]) {
+    let reproducing_fish = population[0];
+    // Reduce timer state for not reproducing fish
+    population.copy_within(1..9, 0);
+    // Handle reproducing fish
+    population[8] = reproducing_fish;
+    population[6] += reproducing_fish;
+}
+
+fn simulate_fish_population(number_of_days: i32, initial_population: &mut [u128; 9]) -> u128 {
+    for _ in 0..number_of_days {
+        simulate_day(initial_population);
+    }
+    // Sum population size
+    initial_population.iter().sum()
+}
+
+fn render_population(population: &[u128; 9]) -> String {
+    // Table of the number of fish per timer state and the population size
+    let mut text = String::from("Timer  Fish\n");
+    for (timer, fish) in population.iter().enumerate() {
+        text.push_str(&format!("{:>5}  {}\n", timer, fish));
+    }
+    text.push_str(&format!("Total  {}\n", population.iter().sum::<u128>()));
+    text
+}
+
+fn generate_timers(rng: &mut Rng, size: usize) -> String {
+    // Timers of the initial fish are between 1 and 5 like in the puzzle
+    let timers = (0..size)
+        .map(|_| rng.range(1..=5).to_string())
+        .collect::<Vec<String>>();
+    format!("{}\n", timers.join(","))
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u32 = 6;
+    const TITLE: &'static str = "Lanternfish";
+    const EXAMPLE: &'static str = EXAMPLE;
+    // The population of a few billion fish still fits into 128 bits after 750 days
+    const PARAMETERS: &'static [Parameter] = &[Parameter {
+        name: "days",
+        description: "Number of simulated days",
+        defaults: [80, 256],
+        minimum: 0,
+        maximum: 750,
+    }];
+
+    type Input = [u128; 9];
+
+    fn parse(input: &str) -> Result<[u128; 9], InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_timers(rng, size)
+    }
+
+    fn part_one(initial_population: &[u128; 9], parameters: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(simulate_fish_population(
+            parameters.get("days") as i32,
+            &mut initial_population.clone(),
+        )))
+    }
+
+    fn part_two(initial_population: &[u128; 9], parameters: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(simulate_fish_population(
+            parameters.get("days") as i32,
+            &mut initial_population.clone(),
+        )))
+    }
+
+    fn frames(
+        initial_population: &[u128; 9],
+        _: Part,
+        parameters: &Parameters,
+        emit: &mut dyn FnMut(Frame),
+    ) {
+        let mut population = *initial_population;
+        emit(Frame::text("day 0", render_population(&population)));
+        for day in 1..=parameters.get("days") {
+            simulate_day(&mut population);
+            emit(Frame::text(
+                format!("day {}", day),
+                render_population(&population),
+            ));
+        }
+    }
+}
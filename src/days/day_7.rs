@@ -0,0 +1,117 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InvalidInput};
+use crate::solution::{Answer, Solution};
+
+// Example crab positions from the puzzle description, used with --example
+const EXAMPLE: &str = "16,1,2,0,4,2,7,1,2,14";
+
+fn parse_input_data(input_data: &str) -> Result<Vec<i32>, InvalidInput> {
+    // Get sorted numeric representation from input string
+    // Sorting for easier access to min, max, and median
+    let line = parse::single_line(Day7::DAY, input_data)?;
+    let mut positions = parse::collect_all(
+        line.text
+            .trim()
+            .split(',')
+            .map(|s| line.parse::<i32>(s, "crab position")),
+    )?;
+    positions.sort();
+    Ok(positions)
+}
+
+fn calculate_linear_fuel_cost(target_position: i32, positions: &[i32]) -> i32 {
+    // Cost for each position is the distance to the target position
+    positions.iter().fold(0, |cost, position| {
+        cost + (position - target_position).abs()
+    })
+}
+
+fn calculate_sum_fuel_cost(target_position: i32, positions: &[i32]) -> i32 {
+    // Cost for each position is the sum from 1 to the distance to the target position
+    // Sum is calculated with Gauss sum
+    positions.iter().fold(0, |cost, position| {
+        let target_distance = (position - target_position).abs();
+        cost + (target_distance * (target_distance + 1)) / 2
+    })
+}
+
+fn find_optimal_position(positions: &[i32], cost_function: fn(i32, &[i32]) -> i32) -> (i32, i32) {
+    // Cost function is convex and can be found with linearly searching in the correct direction
+    // Any encountered local optimum will be the global optimum
+    let min = positions[0];
+    let max = positions[positions.len() - 1];
+
+    // Start with median as starting point for optimum search
+    let mut optimum_position = positions[positions.len() / 2];
+    let mut optimum_cost = cost_function(optimum_position, positions);
+
+    // Check for local optimum at the starting point
+    let predecessor_cost = cost_function(optimum_position - 1, positions);
+    let successor_cost = cost_function(optimum_position + 1, positions);
+    if optimum_position < predecessor_cost && optimum_cost < successor_cost {
+        return (optimum_position, optimum_cost);
+    }
+
+    // Determine optimization search direction
+    let step = if optimum_cost > predecessor_cost {
+        optimum_position -= 1;
+        optimum_cost = predecessor_cost;
+        -1
+    } else {
+        optimum_position += 1;
+        optimum_cost = successor_cost;
+        1
+    };
+
+    // Search stepwise for local optimum
+    while optimum_position >= min && optimum_position <= max {
+        let next_cost = cost_function(optimum_position + step, positions);
+        if next_cost < optimum_cost {
+            optimum_position += step;
+            optimum_cost = next_cost;
+        } else {
+            break;
+        }
+    }
+
+    (optimum_position, optimum_cost)
+}
+
+fn generate_crab_positions(rng: &mut Rng, size: usize) -> String {
+    // Positions are spread over the same range as in the puzzle
+    let positions = (0..size)
+        .map(|_| rng.range(0..=1999).to_string())
+        .collect::<Vec<String>>();
+    format!("{}\n", positions.join(","))
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u32 = 7;
+    const TITLE: &'static str = "The Treachery of Whales";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> Result<Vec<i32>, InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_crab_positions(rng, size)
+    }
+
+    fn part_one(ship_positions: &Vec<i32>, _: &Parameters) -> Result<Answer, String> {
+        let (optimum_position, optimum_cost) =
+            find_optimal_position(ship_positions, calculate_linear_fuel_cost);
+        Ok(Answer::new(optimum_cost).with_extra("position", optimum_position))
+    }
+
+    fn part_two(ship_positions: &Vec<i32>, _: &Parameters) -> Result<Answer, String> {
+        let (optimum_position, optimum_cost) =
+            find_optimal_position(ship_positions, calculate_sum_fuel_cost);
+        Ok(Answer::new(optimum_cost).with_extra("position", optimum_position))
+    }
+}
@@ -0,0 +1,265 @@
+use crate::generate::Rng;
+use crate::parameters::Parameters;
+use crate::parse::{self, InputLine, InvalidInput};
+use crate::solution::{Answer, Solution};
+use std::collections::{HashMap, HashSet};
+
+// Example display notes from the puzzle description, used with --example
+const EXAMPLE: &str =
+    "be cfbegad cbdgef fgaecd cgeb fdcge agebfd fecdb fabcd edb | fdgacbe cefdb cefbgd gcbe
+edbfga begcd cbg gc gcadebf fbgde acbgfd abcde gfcbed gfec | fcgedb cgb dgebacf gc
+fgaebd cg bdaec gdafb agbcfd gdcbef bgcad gfac gcb cdgabef | cg cg fdcagb cbg
+fbegcd cbd adcefb dageb afcb bc aefdc ecdab fgdeca fcdbega | efabcd cedba gadfec cb
+aecbfdg fbg gf bafeg dbefa fcge gcbea fcaegb dgceab fcbdga | gecf egdcabf bgf bfgea
+fgeab ca afcebg bdacfeg cfaedg gcfdb baec bfadeg bafgc acf | gebdcfa ecba ca fadegcb
+dbcfg fgd bdegcaf fgec aegbdf ecdfab fbedc dacgb gdcebf gf | cefg dcbef fcge gbcadfe
+bdfegc cbegaf gecbf dfcage bdacg ed bedf ced adcbefg gebcd | ed bcgafe cdgba cbgef
+egadfb cdbfeg cegd fecab cgb gbdefca cg fgcdab egfdb bfceg | gbdfcae bgc cg cgb
+gcafb gcf dcaebfg ecagb gf abcdeg gaef cafbge fdbac fegbdc | fgae cfgab fg bagce";
+
+// Input signal patterns and output digits of a single display
+pub type DisplayNote = (Vec<String>, Vec<String>);
+
+fn parse_display_note(line: InputLine) -> Result<DisplayNote, InvalidInput> {
+    // Separate line into input signals and outputs
+    // Both are splitted into the indiviual digits
+    // The decoding relies on 10 distinct signal patterns made of the segments a to g
+    let (signal_line, output_line) = line.split_once(line.text, "|")?;
+    let signals = signal_line.split_whitespace().collect::<Vec<&str>>();
+    let outputs = output_line.split_whitespace().collect::<Vec<&str>>();
+    let mut violations = Vec::new();
+    if signals.len() != 10 {
+        violations.push(line.error(signal_line, "Expected 10 signal patterns in"));
+    }
+    for digit in signals.iter().chain(outputs.iter()) {
+        if !digit.chars().all(|c| ('a'..='g').contains(&c)) {
+            violations.push(line.error(digit, "Invalid segments"));
+        }
+    }
+    InvalidInput::from_violations(violations)?;
+
+    // The digits 1, 4, 7 and 8 are found by their unique number of segments,
+    // the other digits are deduced from them and each output digit has to be decoded
+    let mut violations = Vec::new();
+    for (digit, segments) in [(1, 2), (4, 4), (7, 3), (8, 7)] {
+        if !signals.iter().any(|signal| signal.len() == segments) {
+            let message = format!("Missing signal pattern of digit {} in", digit);
+            violations.push(line.error(signal_line, &message));
+        }
+    }
+    InvalidInput::from_violations(violations)?;
+    let signals = signals
+        .into_iter()
+        .map(String::from)
+        .collect::<Vec<String>>();
+    let decoding_dict = create_decoding_dict(&signals);
+    let mut violations = Vec::new();
+    if !(1..=9).contains(&outputs.len()) {
+        violations.push(line.error(output_line, "Expected 1 to 9 output digits in"));
+    }
+    for output in outputs.iter() {
+        if !decoding_dict.contains_key(&sort_digit_chars(output)) {
+            violations.push(line.error(output, "Output digit can not be decoded"));
+        }
+    }
+    InvalidInput::from_violations(violations)?;
+
+    Ok((signals, outputs.into_iter().map(String::from).collect()))
+}
+
+fn parse_input_data(input: &str) -> Result<Vec<DisplayNote>, InvalidInput> {
+    parse::collect_all(parse::lines(Day8::DAY, input).map(parse_display_note))
+}
+
+fn get_digits_with_sizes<'a>(digits: &'a [String], sizes: &[usize]) -> Vec<&'a str> {
+    // Helper function to find digits with certain sizes
+    digits
+        .iter()
+        .map(String::as_str)
+        .filter(|digit| sizes.contains(&digit.len()))
+        .collect()
+}
+
+// Segments of the digits 0 to 9 on a correctly wired display
+const DIGIT_SEGMENTS: [&str; 10] = [
+    "abcefg", "cf", "acdeg", "acdfg", "bcdf", "abdfg", "abdefg", "acf", "abcdefg", "abcdfg",
+];
+
+fn generate_display_notes(rng: &mut Rng, size: usize) -> String {
+    // Each display has its own random wiring, the segments of each digit are listed in random order
+    (0..size)
+        .map(|_| {
+            let mut wiring = "abcdefg".chars().collect::<Vec<char>>();
+            rng.shuffle(&mut wiring);
+            let encode = |rng: &mut Rng, digit: usize| {
+                let mut segments = DIGIT_SEGMENTS[digit]
+                    .bytes()
+                    .map(|segment| wiring[(segment - b'a') as usize])
+                    .collect::<Vec<char>>();
+                rng.shuffle(&mut segments);
+                segments.into_iter().collect::<String>()
+            };
+            let mut digits = (0..10).collect::<Vec<usize>>();
+            rng.shuffle(&mut digits);
+            let signals = digits
+                .into_iter()
+                .map(|digit| encode(rng, digit))
+                .collect::<Vec<String>>();
+            let outputs = (0..4)
+                .map(|_| {
+                    let digit = rng.below(10);
+                    encode(rng, digit)
+                })
+                .collect::<Vec<String>>();
+            format!("{} | {}\n", signals.join(" "), outputs.join(" "))
+        })
+        .collect()
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u32 = 8;
+    const TITLE: &'static str = "Seven Segment Search";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Vec<DisplayNote>;
+
+    fn parse(input: &str) -> Result<Vec<DisplayNote>, InvalidInput> {
+        parse_input_data(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_display_notes(rng, size)
+    }
+
+    fn part_one(inputs: &Vec<DisplayNote>, _: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(count_output_digits_with_unique_sizes(inputs)))
+    }
+
+    fn part_two(inputs: &Vec<DisplayNote>, _: &Parameters) -> Result<Answer, String> {
+        Ok(Answer::new(sum_of_outputs(inputs)))
+    }
+}
+
+// Puzzle 1 //
+
+fn count_output_digits_with_unique_sizes(input: &[DisplayNote]) -> usize {
+    // Filter and count the digits with unique sizes (Digit->Size: 1->2, 7->3, 4->4, 8->7)
+    let unique_sizes = [2, 3, 4, 7];
+    input
+        .iter()
+        .flat_map(|(_, outputs)| get_digits_with_sizes(outputs, &unique_sizes))
+        .count()
+}
+
+// Puzzle 2 //
+
+fn sort_digit_chars(digit: &str) -> String {
+    // Sort the chars of a digit and combine to String for lookup
+    let mut digit_chars: Vec<char> = digit.chars().collect();
+    digit_chars.sort_by(|a, b| b.cmp(a));
+    digit_chars.reverse();
+    String::from_iter(&digit_chars)
+}
+
+fn create_decoding_dict(input_signals: &[String]) -> HashMap<String, char> {
+    // Deduct decoding mapping for digit chars based on the input signals
+    let mut decoding_dict = HashMap::new();
+
+    // At first, find the digits with the unique sizes (Digit->Size: 1->2, 7->3, 4->4, 8->7)
+    // Add their digits to the decoding dictionary
+    // Additionally, create a hash set of chars for each of them to perform set operations
+    let digits_one = input_signals.iter().find(|d| d.len() == 2).unwrap();
+    let digit_set_one = digits_one.chars().collect::<HashSet<char>>();
+    decoding_dict.insert(sort_digit_chars(digits_one), '1');
+
+    let digits_four = input_signals.iter().find(|d| d.len() == 4).unwrap();
+    let digit_set_four = digits_four.chars().collect::<HashSet<char>>();
+    decoding_dict.insert(sort_digit_chars(digits_four), '4');
+
+    let digits_seven = input_signals.iter().find(|d| d.len() == 3).unwrap();
+    let digit_set_seven = digits_seven.chars().collect::<HashSet<char>>();
+    decoding_dict.insert(sort_digit_chars(digits_seven), '7');
+
+    let digits_eight = input_signals.iter().find(|d| d.len() == 7).unwrap();
+    let digit_set_eight = digits_eight.chars().collect::<HashSet<char>>();
+    decoding_dict.insert(sort_digit_chars(digits_eight), '8');
+
+    // Encoded a is the set difference of 7 (a, c, f) and 1 (c, f)
+    let encoded_a = *digit_set_seven.difference(&digit_set_one).next().unwrap();
+    let a_set = [encoded_a].iter().cloned().collect::<HashSet<char>>();
+
+    // Create two candidate sets with two candidates each to distinguish the remaining numbers
+    // Candidates c + f: Take from encoded 1
+    let candidates_c_f = digit_set_one;
+    // Candidates e + g: Difference of 8 (a, b, c, d, e, f, g) and the union of 4 (b, c, d, f) and a
+    let candidates_e_g = digit_set_eight
+        .difference(
+            &digit_set_four
+                .union(&a_set)
+                .cloned()
+                .collect::<HashSet<char>>(),
+        )
+        .cloned()
+        .collect::<HashSet<char>>();
+
+    // Remaining digits:
+    // Digits with size 5:
+    // 2 (a, c, d, e, g)
+    // 3 (a, c, d, f, g)
+    // 5 (a, b, d, f, g)
+    // Digits with size 6:
+    // 0 (a, b, c, e, f, g)
+    // 6 (a, b, d, e, f, g)
+    // 9 (a, b, c, d, f, g)
+    // They can be distinguished via the combination of:
+    // 1) digit size
+    // 2) intersection with c + f
+    // 3) intersection with e + g
+    let remaining_digits: Vec<(HashSet<char>, String, usize)> =
+        get_digits_with_sizes(input_signals, &[5, 6])
+            .iter()
+            .map(|digit| {
+                (
+                    digit.chars().collect::<HashSet<char>>(),
+                    sort_digit_chars(digit),
+                    digit.len(),
+                )
+            })
+            .collect();
+    for (digit_chars_set, digit_key, digit_len) in remaining_digits {
+        match (
+            digit_len,
+            digit_chars_set.intersection(&candidates_c_f).count(),
+            digit_chars_set.intersection(&candidates_e_g).count(),
+        ) {
+            (5, 1, 2) => decoding_dict.insert(digit_key, '2'),
+            (5, 2, 1) => decoding_dict.insert(digit_key, '3'),
+            (5, 1, 1) => decoding_dict.insert(digit_key, '5'),
+            (6, 2, 2) => decoding_dict.insert(digit_key, '0'),
+            (6, 1, 2) => decoding_dict.insert(digit_key, '6'),
+            (6, 2, 1) => decoding_dict.insert(digit_key, '9'),
+            _ => None,
+        };
+    }
+
+    decoding_dict
+}
+
+fn sum_of_outputs(input: &[DisplayNote]) -> i32 {
+    // 1) Ceate a decoding dictionary based on the input signals for each line
+    // 2) Use these dictionaries to decode the outputs of each line and create the output number
+    // 3) Calculate the sum of all output line numbers
+    input
+        .iter()
+        .map(|(input_signals, outputs)| {
+            let decoding_dict = create_decoding_dict(input_signals);
+            let output_number_string =
+                String::from_iter(outputs.iter().map(|output_digit| {
+                    decoding_dict.get(&sort_digit_chars(output_digit)).unwrap()
+                }));
+            output_number_string.parse::<i32>().unwrap()
+        })
+        .sum()
+}
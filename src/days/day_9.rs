@@ -0,0 +1,220 @@
+use crate::generate::Rng;
+use crate::grid::Grid;
+use crate::netpbm::{Image, Rgb};
+use crate::parameters::Parameters;
+use crate::parse::InvalidInput;
+use crate::solution::{Answer, Part, Solution};
+use std::collections::HashSet;
+
+// Example height map from the puzzle description, used with --example
+const EXAMPLE: &str = "2199943210
+3987894921
+9856789892
+8767896789
+9899965678";
+
+fn parse_input(input: &str) -> Result<Grid<u32>, InvalidInput> {
+    // Create numerical height map representation from input lines
+    Grid::parse_digits(Day9::DAY, input)
+}
+
+fn is_local_minimum(x: u32, neighbors: Vec<u32>) -> bool {
+    // Check if all four neighbors have higher values
+    neighbors.iter().cloned().all(|height| height > x)
+}
+
+fn find_local_minima(height_map: &Grid<u32>) -> Vec<(usize, usize)> {
+    // Search the map for local minima and collect their coordinates
+    let mut local_minima = Vec::new();
+    for position in height_map.positions() {
+        let neighbor_heights = height_map
+            .neighbors_4(position)
+            .map(|neighbor| height_map[neighbor])
+            .collect();
+
+        if is_local_minimum(height_map[position], neighbor_heights) {
+            local_minima.push(position);
+        }
+    }
+    local_minima
+}
+
+// Puzzle 1 //
+
+fn sum_coordinate_heights(coordinates: &[(usize, usize)], height_map: &Grid<u32>) -> u32 {
+    // Get the heights+1 of a list of coordinates and calculate their sum
+    coordinates
+        .iter()
+        .map(|position| height_map[*position] + 1)
+        .sum()
+}
+
+// Puzzle 2 //
+
+fn get_basin_fields(minimum: (usize, usize), height_map: &Grid<u32>) -> HashSet<(usize, usize)> {
+    // Extend basin from minimum iteratively until the basin edges (fields with a 9) are reached
+    // Store each basin field in a hash set to prevent duplicate extensions
+    let mut basin_fields = HashSet::<(usize, usize)>::new();
+    basin_fields.insert(minimum);
+    let mut basin_borders_reached = false;
+
+    while !basin_borders_reached {
+        basin_borders_reached = true;
+
+        for position in basin_fields.clone() {
+            let unchecked_neighbors = height_map
+                .neighbors_4(position)
+                .filter(|neighbor| !(height_map[*neighbor] == 9 || basin_fields.contains(neighbor)))
+                .collect::<HashSet<(usize, usize)>>();
+
+            if !unchecked_neighbors.is_empty() {
+                basin_borders_reached = false;
+                basin_fields = basin_fields.union(&unchecked_neighbors).cloned().collect();
+            }
+        }
+    }
+    basin_fields
+}
+
+fn get_basin_size(minimum: (usize, usize), height_map: &Grid<u32>) -> usize {
+    get_basin_fields(minimum, height_map).len()
+}
+
+fn get_three_largest_basin_sizes(
+    local_minima: &[(usize, usize)],
+    height_map: &Grid<u32>,
+) -> Vec<usize> {
+    // Get the basin size starting at each local optimum
+    // Return the sizes of the three largest
+    let mut basin_sizes = local_minima
+        .iter()
+        .cloned()
+        .map(|basin_minimum| get_basin_size(basin_minimum, height_map))
+        .collect::<Vec<usize>>();
+
+    basin_sizes.sort();
+    basin_sizes.reverse();
+
+    basin_sizes.into_iter().take(3).collect()
+}
+
+fn generate_height_map(rng: &mut Rng, size: usize) -> String {
+    // Each basin is formed around a random low point, one in every 8x8 block of a square map
+    // Heights increase with the distance to the nearest low point
+    // Positions at about the same distance to two low points form ridges of 9s between the basins
+    const BLOCK_SIZE: usize = 8;
+    let blocks = size.div_ceil(BLOCK_SIZE);
+    let low_points = Grid::from_fn(blocks, blocks, |(x, y)| {
+        (
+            x * BLOCK_SIZE + rng.below(BLOCK_SIZE),
+            y * BLOCK_SIZE + rng.below(BLOCK_SIZE),
+        )
+    });
+    let height_map = Grid::from_fn(size, size, |(x, y)| {
+        // The nearest low points are at most two blocks away
+        let (block_x, block_y) = ((x / BLOCK_SIZE) as isize, (y / BLOCK_SIZE) as isize);
+        let mut distances = (-2..=2)
+            .flat_map(|d_y| (-2..=2).map(move |d_x| (block_x + d_x, block_y + d_y)))
+            .filter_map(|(block_x, block_y)| low_points.checked_position(block_x, block_y))
+            .map(|block| {
+                let (low_x, low_y) = low_points[block];
+                low_x.abs_diff(x) + low_y.abs_diff(y)
+            })
+            .collect::<Vec<usize>>();
+        distances.sort();
+        match distances[..] {
+            [nearest, second_nearest, ..] if second_nearest - nearest <= 1 => '9',
+            [nearest, ..] => char::from_digit(usize::min(nearest, 8) as u32, 10).unwrap(),
+            [] => '9',
+        }
+    });
+    height_map
+        .rows()
+        .map(|row| format!("{}\n", row.iter().collect::<String>()))
+        .collect()
+}
+
+// Image //
+
+// Colors of the three largest basins, all other basins are gray
+const LARGEST_BASIN_COLORS: [Rgb; 3] = [Rgb(230, 60, 60), Rgb(60, 200, 90), Rgb(70, 110, 230)];
+
+fn shade(Rgb(red, green, blue): Rgb, height: u32) -> Rgb {
+    // Deeper locations are brighter
+    let factor = |channel: u8| (channel as u32 * (12 - height) / 12) as u8;
+    Rgb(factor(red), factor(green), factor(blue))
+}
+
+fn render_low_points(height_map: &Grid<u32>) -> Grid<Rgb> {
+    // Heights in gray with the low points in red
+    let local_minima = find_local_minima(height_map)
+        .into_iter()
+        .collect::<HashSet<_>>();
+    Grid::from_fn(height_map.width(), height_map.height(), |position| {
+        if local_minima.contains(&position) {
+            LARGEST_BASIN_COLORS[0]
+        } else {
+            shade(Rgb::WHITE, height_map[position])
+        }
+    })
+}
+
+fn render_basins(height_map: &Grid<u32>) -> Grid<Rgb> {
+    // Basins shaded by height, the edges of the basins (fields with a 9) are black
+    let mut basins = find_local_minima(height_map)
+        .into_iter()
+        .map(|minimum| get_basin_fields(minimum, height_map))
+        .collect::<Vec<HashSet<(usize, usize)>>>();
+    basins.sort_by_key(|basin| std::cmp::Reverse(basin.len()));
+
+    let mut image = height_map.map(|_| Rgb::BLACK);
+    for (i, basin) in basins.iter().enumerate() {
+        let color = LARGEST_BASIN_COLORS
+            .get(i)
+            .cloned()
+            .unwrap_or(Rgb(160, 160, 160));
+        for position in basin {
+            image[*position] = shade(color, height_map[*position]);
+        }
+    }
+    image
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u32 = 9;
+    const TITLE: &'static str = "Smoke Basin";
+    const EXAMPLE: &'static str = EXAMPLE;
+
+    type Input = Grid<u32>;
+
+    fn parse(input: &str) -> Result<Grid<u32>, InvalidInput> {
+        parse_input(input)
+    }
+
+    fn generate(rng: &mut Rng, size: usize) -> String {
+        generate_height_map(rng, size)
+    }
+
+    fn part_one(height_map: &Grid<u32>, _: &Parameters) -> Result<Answer, String> {
+        let local_minima = find_local_minima(height_map);
+        Ok(Answer::new(sum_coordinate_heights(
+            &local_minima,
+            height_map,
+        )))
+    }
+
+    fn part_two(height_map: &Grid<u32>, _: &Parameters) -> Result<Answer, String> {
+        let local_minima = find_local_minima(height_map);
+        let basin_sizes = get_three_largest_basin_sizes(&local_minima, height_map);
+        Ok(Answer::new(basin_sizes.iter().product::<usize>()))
+    }
+
+    fn image(height_map: &Grid<u32>, part: Part, _: &Parameters) -> Option<Image> {
+        Some(Image::Pixmap(match part {
+            Part::One => render_low_points(height_map),
+            Part::Two => render_basins(height_map),
+        }))
+    }
+}
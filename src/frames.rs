@@ -0,0 +1,78 @@
+use crate::netpbm::Image;
+use crate::solution::Part;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+pub const INDEX_HEADER: &str = "part,frame,file,label";
+
+// Snapshot of a simulation state, either as image or as text for states without a 2d layout
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FrameContent {
+    Image(Image),
+    Text(String),
+}
+
+// Single step of a simulation, the label names the step, e.g. "step 3" or "fold along x=5"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub label: String,
+    pub content: FrameContent,
+}
+
+impl Frame {
+    pub fn image(label: impl Into<String>, image: Image) -> Frame {
+        Frame {
+            label: label.into(),
+            content: FrameContent::Image(image),
+        }
+    }
+
+    pub fn text(label: impl Into<String>, text: impl Into<String>) -> Frame {
+        Frame {
+            label: label.into(),
+            content: FrameContent::Text(text.into()),
+        }
+    }
+}
+
+// Writes numbered frame files into a directory and lists them in an index.csv file
+// Frame numbers are padded to keep the files in order when sorted by name
+pub struct FrameWriter {
+    directory: PathBuf,
+    index: File,
+}
+
+impl FrameWriter {
+    pub fn create(directory: &Path) -> io::Result<FrameWriter> {
+        fs::create_dir_all(directory)?;
+        let mut index = File::create(directory.join("index.csv"))?;
+        writeln!(index, "{}", INDEX_HEADER)?;
+        Ok(FrameWriter {
+            directory: directory.to_path_buf(),
+            index,
+        })
+    }
+
+    pub fn write(&mut self, part: Part, number: usize, frame: &Frame) -> io::Result<()> {
+        let extension = match &frame.content {
+            FrameContent::Image(image) => image.extension(),
+            FrameContent::Text(_) => "txt",
+        };
+        let file_name = format!("part_{}_frame_{:06}.{}", part.number(), number, extension);
+        let mut file = File::create(self.directory.join(&file_name))?;
+        match &frame.content {
+            FrameContent::Image(image) => image.write(&mut file)?,
+            FrameContent::Text(text) => file.write_all(text.as_bytes())?,
+        }
+        // Labels are plain words and numbers, commas would break the columns
+        writeln!(
+            self.index,
+            "{},{},{},{}",
+            part.number(),
+            number,
+            file_name,
+            frame.label.replace(',', ";")
+        )
+    }
+}
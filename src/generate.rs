@@ -0,0 +1,72 @@
+use std::ops::RangeInclusive;
+
+// Seeded pseudo random number generator for synthetic puzzle inputs
+// The same seed always creates the same sequence, independent of platform and build
+// SplitMix64 is good enough for inputs and needs no dependencies
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Rng {
+        Rng { state: seed }
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e37_79b9_7f4a_7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+        z ^ (z >> 31)
+    }
+
+    pub fn below(&mut self, bound: usize) -> usize {
+        // Uniform number in 0..bound, the bound has to be positive
+        assert!(bound > 0, "Random numbers need a positive bound");
+        ((self.next_u64() as u128 * bound as u128) >> 64) as usize
+    }
+
+    pub fn range(&mut self, range: RangeInclusive<i64>) -> i64 {
+        let (start, end) = (*range.start(), *range.end());
+        assert!(start <= end, "Random numbers need a non-empty range");
+        let width = (end as i128 - start as i128 + 1) as u128;
+        (start as i128 + ((self.next_u64() as u128 * width) >> 64) as i128) as i64
+    }
+
+    pub fn chance(&mut self, probability: f64) -> bool {
+        // Use the upper 53 bits for a uniform float in [0, 1)
+        ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < probability
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len())]
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        // Fisher-Yates shuffle
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+pub fn grid(
+    rng: &mut Rng,
+    width: usize,
+    height: usize,
+    mut cell: impl FnMut(&mut Rng) -> char,
+) -> String {
+    // Rows of random chars, each row on its own line
+    let mut text = String::with_capacity((width + 1) * height);
+    for _ in 0..height {
+        text.extend((0..width).map(|_| cell(rng)));
+        text.push('\n');
+    }
+    text
+}
+
+pub fn digit(rng: &mut Rng, digits: RangeInclusive<u32>) -> char {
+    let digit = rng.range(*digits.start() as i64..=*digits.end() as i64);
+    char::from_digit(digit as u32, 10).unwrap()
+}
@@ -0,0 +1,218 @@
+use crate::json::Json;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, RangeInclusive, Sub, SubAssign};
+
+// Integer vector of a position or distance in the 3d space
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub struct Vec3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Vec3 {
+    pub const ZERO: Vec3 = Vec3 { x: 0, y: 0, z: 0 };
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Vec3 {
+        Vec3 { x, y, z }
+    }
+
+    pub fn manhattan_norm(&self) -> i64 {
+        self.x.abs() + self.y.abs() + self.z.abs()
+    }
+
+    pub fn chebyshev_norm(&self) -> i64 {
+        i64::max(self.x.abs(), i64::max(self.y.abs(), self.z.abs()))
+    }
+
+    pub fn squared_norm(&self) -> i64 {
+        self.x * self.x + self.y * self.y + self.z * self.z
+    }
+
+    pub fn manhattan_distance(&self, other: Vec3) -> i64 {
+        (*self - other).manhattan_norm()
+    }
+
+    pub fn component_min(&self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            i64::min(self.x, other.x),
+            i64::min(self.y, other.y),
+            i64::min(self.z, other.z),
+        )
+    }
+
+    pub fn component_max(&self, other: Vec3) -> Vec3 {
+        Vec3::new(
+            i64::max(self.x, other.x),
+            i64::max(self.y, other.y),
+            i64::max(self.z, other.z),
+        )
+    }
+
+    pub fn rotate(&self, rotation: Rotation) -> Vec3 {
+        rotation.apply(*self)
+    }
+}
+
+impl fmt::Display for Vec3 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl Add for Vec3 {
+    type Output = Vec3;
+
+    fn add(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Vec3 {
+    type Output = Vec3;
+
+    fn sub(self, other: Vec3) -> Vec3 {
+        Vec3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl Neg for Vec3 {
+    type Output = Vec3;
+
+    fn neg(self) -> Vec3 {
+        Vec3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+impl Mul<i64> for Vec3 {
+    type Output = Vec3;
+
+    fn mul(self, factor: i64) -> Vec3 {
+        Vec3::new(self.x * factor, self.y * factor, self.z * factor)
+    }
+}
+
+impl AddAssign for Vec3 {
+    fn add_assign(&mut self, other: Vec3) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Vec3 {
+    fn sub_assign(&mut self, other: Vec3) {
+        *self = *self - other;
+    }
+}
+
+impl From<Vec3> for Json {
+    fn from(vector: Vec3) -> Json {
+        Json::from((vector.x, vector.y, vector.z))
+    }
+}
+
+// One of the 24 rotations by multiples of 90° that keep a coordinate system right-handed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rotation(u8);
+
+impl Rotation {
+    pub const IDENTITY: Rotation = Rotation(0);
+
+    pub fn all() -> impl Iterator<Item = Rotation> {
+        (0..24).map(Rotation)
+    }
+
+    pub fn apply(&self, vector: Vec3) -> Vec3 {
+        // Each rotation is a permutation of the axes with sign changes
+        let Vec3 { x, y, z } = vector;
+        let (x, y, z) = match self.0 {
+            1 => (x, -y, -z),
+            2 => (x, -z, y),
+            3 => (x, z, -y),
+            4 => (-x, -y, z),
+            5 => (-x, -z, -y),
+            6 => (-x, y, -z),
+            7 => (-x, z, y),
+            8 => (y, z, x),
+            9 => (y, -x, z),
+            10 => (y, -z, -x),
+            11 => (y, x, -z),
+            12 => (-y, x, z),
+            13 => (-y, -z, x),
+            14 => (-y, -x, -z),
+            15 => (-y, z, -x),
+            16 => (z, x, y),
+            17 => (z, y, -x),
+            18 => (z, -x, -y),
+            19 => (z, -y, x),
+            20 => (-z, y, x),
+            21 => (-z, x, -y),
+            22 => (-z, -y, -x),
+            23 => (-z, -x, y),
+            _ => (x, y, z),
+        };
+        Vec3::new(x, y, z)
+    }
+}
+
+// Axis-aligned box of integer positions, both corners are part of the box
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Aabb {
+    pub min: Vec3,
+    pub max: Vec3,
+}
+
+impl Aabb {
+    pub fn new(corner_1: Vec3, corner_2: Vec3) -> Aabb {
+        // The corners can be given in any order
+        Aabb {
+            min: corner_1.component_min(corner_2),
+            max: corner_1.component_max(corner_2),
+        }
+    }
+
+    pub fn from_ranges(
+        x_range: RangeInclusive<i64>,
+        y_range: RangeInclusive<i64>,
+        z_range: RangeInclusive<i64>,
+    ) -> Aabb {
+        Aabb::new(
+            Vec3::new(*x_range.start(), *y_range.start(), *z_range.start()),
+            Vec3::new(*x_range.end(), *y_range.end(), *z_range.end()),
+        )
+    }
+
+    pub fn volume(&self) -> Option<i64> {
+        // Number of integer positions inside of the box, None if it exceeds the range of i64
+        let edge = |min: i64, max: i64| max.checked_sub(min)?.checked_add(1);
+        edge(self.min.x, self.max.x)?
+            .checked_mul(edge(self.min.y, self.max.y)?)?
+            .checked_mul(edge(self.min.z, self.max.z)?)
+    }
+
+    pub fn contains(&self, point: Vec3) -> bool {
+        self.min.component_max(point) == point && self.max.component_min(point) == point
+    }
+
+    pub fn contains_box(&self, other: &Aabb) -> bool {
+        self.contains(other.min) && self.contains(other.max)
+    }
+
+    pub fn intersection(&self, other: &Aabb) -> Option<Aabb> {
+        // Boxes intersect if their ranges overlap on every axis
+        let min = self.min.component_max(other.min);
+        let max = self.max.component_min(other.max);
+        if min.x <= max.x && min.y <= max.y && min.z <= max.z {
+            Some(Aabb { min, max })
+        } else {
+            None
+        }
+    }
+
+    pub fn bounding_box(&self, other: &Aabb) -> Aabb {
+        // Smallest box containing both boxes
+        Aabb {
+            min: self.min.component_min(other.min),
+            max: self.max.component_max(other.max),
+        }
+    }
+}
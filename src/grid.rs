@@ -0,0 +1,212 @@
+use crate::parse::{self, InvalidInput};
+use std::ops::{Index, IndexMut};
+
+// Offsets of the horizontal and vertical neighbors
+pub const NEIGHBORS_4: [(isize, isize); 4] = [(0, -1), (-1, 0), (1, 0), (0, 1)];
+
+// Offsets of the horizontal, vertical and diagonal neighbors
+pub const NEIGHBORS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (0, -1),
+    (1, -1),
+    (-1, 0),
+    (1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+];
+
+// Rectangular grid of cells addressed by (x, y) positions
+// The cells are stored row by row in a single vector
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn from_fn(
+        width: usize,
+        height: usize,
+        mut cell: impl FnMut((usize, usize)) -> T,
+    ) -> Grid<T> {
+        Grid {
+            width,
+            height,
+            cells: (0..width * height)
+                .map(|index| cell((index % width, index / width)))
+                .collect(),
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Grid<T>> {
+        // All rows need the same length
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    pub fn parse_chars(
+        day: u32,
+        input: &str,
+        description: &str,
+        cell: impl Fn(char) -> Option<T>,
+    ) -> Result<Grid<T>, InvalidInput> {
+        // Parse each char of the input lines into a cell of a rectangular grid
+        // The width of the first row is expected for all rows, every invalid cell is reported
+        let mut width = None;
+        let rows = parse::collect_all(parse::lines(day, input).map(|line| {
+            let row = parse::collect_all(line.text.char_indices().map(|(index, c)| {
+                cell(c).ok_or_else(|| {
+                    line.error(
+                        &line.text[index..index + c.len_utf8()],
+                        &format!("Invalid {}", description),
+                    )
+                })
+            }));
+            let row_width = line.text.chars().count();
+            let width_check = match *width.get_or_insert(row_width) {
+                _ if row_width == 0 => Err(line.end_error("Empty grid row")),
+                width if width == row_width => Ok(()),
+                width => Err(line.error(
+                    line.text,
+                    &format!("Expected a row with {} cells instead of", width),
+                )),
+            };
+            parse::combine(row, width_check.map_err(InvalidInput::from)).map(|(row, ())| row)
+        }))?;
+        if rows.is_empty() {
+            return Err(parse::end_of_input(day, input)
+                .error("", "Missing grid rows")
+                .into());
+        }
+        Ok(Grid::from_rows(rows).unwrap())
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (x, y): (usize, usize)) -> Option<&T> {
+        if x < self.width && y < self.height {
+            self.cells.get(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, (x, y): (usize, usize)) -> Option<&mut T> {
+        if x < self.width && y < self.height {
+            self.cells.get_mut(y * self.width + x)
+        } else {
+            None
+        }
+    }
+
+    pub fn checked_position(&self, x: isize, y: isize) -> Option<(usize, usize)> {
+        // Position inside of the grid for possibly negative or out of bounds coordinates
+        if (0..self.width as isize).contains(&x) && (0..self.height as isize).contains(&y) {
+            Some((x as usize, y as usize))
+        } else {
+            None
+        }
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        // All positions in row-major order
+        let width = self.width;
+        (0..self.cells.len()).map(move |index| (index % width, index / width))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
+        self.cells.iter_mut()
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    pub fn neighbors(
+        &self,
+        (x, y): (usize, usize),
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        // Positions of the neighbors with the given offsets that are inside of the grid
+        offsets
+            .iter()
+            .filter_map(move |(d_x, d_y)| self.checked_position(x as isize + d_x, y as isize + d_y))
+    }
+
+    pub fn neighbors_4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &NEIGHBORS_4)
+    }
+
+    pub fn neighbors_8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(position, &NEIGHBORS_8)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl Grid<u32> {
+    pub fn parse_digits(day: u32, input: &str) -> Result<Grid<u32>, InvalidInput> {
+        Grid::parse_chars(day, input, "digit", |c| c.to_digit(10))
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, (x, y): (usize, usize)) -> &T {
+        assert!(
+            x < self.width && y < self.height,
+            "Position ({}, {}) is outside of the {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        &self.cells[y * self.width + x]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, (x, y): (usize, usize)) -> &mut T {
+        assert!(
+            x < self.width && y < self.height,
+            "Position ({}, {}) is outside of the {}x{} grid",
+            x,
+            y,
+            self.width,
+            self.height
+        );
+        &mut self.cells[y * self.width + x]
+    }
+}
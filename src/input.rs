@@ -0,0 +1,102 @@
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::PathBuf;
+
+// Where the puzzle input of a day is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    Example,
+    Stdin,
+    File(PathBuf),
+}
+
+#[derive(Debug)]
+pub enum InputError {
+    UnknownOption(String),
+    TooManyArguments(String),
+    Unreadable(InputSource, io::Error),
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputSource::Example => write!(f, "embedded example"),
+            InputSource::Stdin => write!(f, "stdin"),
+            InputSource::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::UnknownOption(option) => write!(f, "Unknown option '{}'", option),
+            InputError::TooManyArguments(argument) => {
+                write!(
+                    f,
+                    "Unexpected argument '{}', only one input is allowed",
+                    argument
+                )
+            }
+            InputError::Unreadable(source, error) => {
+                write!(f, "Unable to read input from {}: {}", source, error)
+            }
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+impl InputSource {
+    pub fn default_for_day(day: u32) -> InputSource {
+        // Personal puzzle inputs are expected at inputs/day_N.txt
+        InputSource::File(PathBuf::from(format!("inputs/day_{}.txt", day)))
+    }
+
+    pub fn from_args<I>(day: u32, args: I) -> Result<InputSource, InputError>
+    where
+        I: IntoIterator<Item = String>,
+    {
+        // Accept a single optional input argument:
+        // --example -> embedded example, '-' -> stdin, anything else -> file path
+        let mut source = None;
+        for argument in args {
+            let next_source = match argument.as_str() {
+                "--example" => InputSource::Example,
+                "-" => InputSource::Stdin,
+                a if a.starts_with("--") => return Err(InputError::UnknownOption(argument)),
+                a => InputSource::File(PathBuf::from(a)),
+            };
+            if source.is_some() {
+                return Err(InputError::TooManyArguments(argument));
+            }
+            source = Some(next_source);
+        }
+        Ok(source.unwrap_or_else(|| InputSource::default_for_day(day)))
+    }
+
+    pub fn read(&self, example: &str) -> Result<String, InputError> {
+        // Read the complete input text of the source
+        let result = match self {
+            InputSource::Example => Ok(String::from(example)),
+            InputSource::Stdin => {
+                let mut text = String::new();
+                io::stdin().read_to_string(&mut text).map(|_| text)
+            }
+            InputSource::File(path) => fs::read_to_string(path),
+        };
+        result.map_err(|error| InputError::Unreadable(self.clone(), error))
+    }
+
+    pub fn open<'a>(&self, example: &'a str) -> Result<Box<dyn BufRead + 'a>, InputError> {
+        // Stream the input of the source, only opening a file can fail here
+        match self {
+            InputSource::Example => Ok(Box::new(example.as_bytes())),
+            InputSource::Stdin => Ok(Box::new(io::stdin().lock())),
+            InputSource::File(path) => fs::File::open(path)
+                .map(|file| Box::new(BufReader::new(file)) as Box<dyn BufRead>)
+                .map_err(|error| InputError::Unreadable(self.clone(), error)),
+        }
+    }
+}
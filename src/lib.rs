@@ -0,0 +1,20 @@
+pub mod bench;
+pub mod cycle;
+pub mod days;
+pub mod frames;
+pub mod generate;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod json;
+pub mod log;
+pub mod netpbm;
+pub mod parameters;
+pub mod parse;
+pub mod pool;
+pub mod registry;
+pub mod report;
+pub mod solution;
+pub mod verify;
+
+pub use solution::{Answer, Part, Puzzle, Solution, SolveError};
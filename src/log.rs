@@ -0,0 +1,115 @@
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Verbosity of the log, each level includes the messages of the levels before it
+// Solvers log progress with info, intermediate states with debug and single operations with trace
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Quiet,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    pub fn from_verbosity(verbosity: usize) -> Level {
+        match verbosity {
+            0 => Level::Quiet,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+
+    fn from_u8(level: u8) -> Level {
+        match level {
+            0 => Level::Quiet,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pad to support width and alignment of the log lines
+        match self {
+            Level::Quiet => f.pad("quiet"),
+            Level::Info => f.pad("info"),
+            Level::Debug => f.pad("debug"),
+            Level::Trace => f.pad("trace"),
+        }
+    }
+}
+
+// The level is shared by all threads, the library is quiet unless the application enables logging
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Quiet as u8);
+
+thread_local! {
+    // Label of the current work of the thread, e.g. the day and part being solved by a worker
+    static SCOPE: RefCell<Option<String>> = const { RefCell::new(None) };
+}
+
+pub fn set_level(level: Level) {
+    MAX_LEVEL.store(level as u8, Ordering::Relaxed);
+}
+
+pub fn level() -> Level {
+    Level::from_u8(MAX_LEVEL.load(Ordering::Relaxed))
+}
+
+pub fn enabled(level: Level) -> bool {
+    level != Level::Quiet && level <= self::level()
+}
+
+pub fn with_scope<R>(scope: String, f: impl FnOnce() -> R) -> R {
+    // Label all messages of the current thread while running f, the previous label is restored afterwards
+    // The label is restored on drop, so a worker that caught a panic in f does not keep the label
+    struct RestoreScope(Option<String>);
+    impl Drop for RestoreScope {
+        fn drop(&mut self) {
+            SCOPE.with(|current| *current.borrow_mut() = self.0.take());
+        }
+    }
+    let _restore = RestoreScope(SCOPE.with(|current| current.replace(Some(scope))));
+    f()
+}
+
+pub fn write(level: Level, message: fmt::Arguments) {
+    // Messages go to stderr, stdout is reserved for the results of the application
+    // A single eprintln keeps the lines of parallel workers from interleaving
+    SCOPE.with(|scope| match &*scope.borrow() {
+        Some(scope) => eprintln!("{:<5} {}: {}", level, scope, message),
+        None => eprintln!("{:<5} {}", level, message),
+    });
+}
+
+// The level is checked before the message is formatted, so disabled messages cost almost nothing
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Info) {
+            $crate::log::write($crate::log::Level::Info, format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Debug) {
+            $crate::log::write($crate::log::Level::Debug, format_args!($($arg)*));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)*) => {
+        if $crate::log::enabled($crate::log::Level::Trace) {
+            $crate::log::write($crate::log::Level::Trace, format_args!($($arg)*));
+        }
+    };
+}
@@ -0,0 +1,98 @@
+use crate::grid::Grid;
+use std::io::{self, Write};
+
+// Color of a pixmap pixel
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+}
+
+// Image in one of the Netpbm formats, written in their binary variants
+// Any image viewer supporting Netpbm can show them without further conversion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Image {
+    // PBM, set pixels are black
+    Bitmap(Grid<bool>),
+    // PGM with the brightest value, values above it are clamped
+    Graymap(Grid<u16>, u16),
+    // PPM
+    Pixmap(Grid<Rgb>),
+}
+
+impl Image {
+    pub fn graymap(values: &Grid<u16>) -> Image {
+        // The brightest value of the image is white, a black image still needs a positive maximum
+        let max_value = values.iter().cloned().fold(1, u16::max);
+        Image::Graymap(values.clone(), max_value)
+    }
+
+    pub fn width(&self) -> usize {
+        match self {
+            Image::Bitmap(pixels) => pixels.width(),
+            Image::Graymap(pixels, _) => pixels.width(),
+            Image::Pixmap(pixels) => pixels.width(),
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        match self {
+            Image::Bitmap(pixels) => pixels.height(),
+            Image::Graymap(pixels, _) => pixels.height(),
+            Image::Pixmap(pixels) => pixels.height(),
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Image::Bitmap(_) => "pbm",
+            Image::Graymap(_, _) => "pgm",
+            Image::Pixmap(_) => "ppm",
+        }
+    }
+
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        let mut data = Vec::new();
+        match self {
+            Image::Bitmap(pixels) => {
+                // Rows are packed into bytes, 8 pixels per byte with the first pixel in the highest bit
+                writeln!(data, "P4\n{} {}", pixels.width(), pixels.height())?;
+                for row in pixels.rows() {
+                    data.extend(row.chunks(8).map(|byte_pixels| {
+                        byte_pixels
+                            .iter()
+                            .enumerate()
+                            .fold(0u8, |byte, (i, pixel)| byte | ((*pixel as u8) << (7 - i)))
+                    }));
+                }
+            }
+            Image::Graymap(pixels, max_value) => {
+                // Values above 255 need two bytes per pixel, most significant byte first
+                let max_value = u16::max(1, *max_value);
+                writeln!(
+                    data,
+                    "P5\n{} {}\n{}",
+                    pixels.width(),
+                    pixels.height(),
+                    max_value
+                )?;
+                for value in pixels.iter().map(|value| u16::min(*value, max_value)) {
+                    if max_value > 255 {
+                        data.extend(value.to_be_bytes());
+                    } else {
+                        data.push(value as u8);
+                    }
+                }
+            }
+            Image::Pixmap(pixels) => {
+                writeln!(data, "P6\n{} {}\n255", pixels.width(), pixels.height())?;
+                for Rgb(red, green, blue) in pixels.iter() {
+                    data.extend([*red, *green, *blue]);
+                }
+            }
+        }
+        writer.write_all(&data)
+    }
+}
@@ -0,0 +1,83 @@
+use crate::solution::Part;
+use std::fmt;
+
+// Day specific knob of a solution, e.g. the number of simulated days
+// Each part has its own default, an override from the command line applies to both parts
+// Overrides are limited to the range the solution can handle without overflow or excessive memory
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Parameter {
+    pub name: &'static str,
+    pub description: &'static str,
+    pub defaults: [i64; 2],
+    pub minimum: i64,
+    pub maximum: i64,
+}
+
+impl Parameter {
+    pub fn default_for(&self, part: Part) -> i64 {
+        match part {
+            Part::One => self.defaults[0],
+            Part::Two => self.defaults[1],
+        }
+    }
+
+    pub fn check(&self, value: i64) -> Result<(), String> {
+        if (self.minimum..=self.maximum).contains(&value) {
+            Ok(())
+        } else {
+            Err(format!(
+                "--{} has to be between {} and {}, found {}",
+                self.name, self.minimum, self.maximum, value
+            ))
+        }
+    }
+}
+
+impl fmt::Display for Parameter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Shared defaults are only shown once, pad to support width and alignment in listings
+        let option = match self.defaults {
+            [part_one, part_two] if part_one == part_two => format!("--{} {}", self.name, part_one),
+            [part_one, part_two] => format!("--{} {}/{}", self.name, part_one, part_two),
+        };
+        f.pad(&option)
+    }
+}
+
+// Values of the declared parameters of a day for solving a single part
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Parameters {
+    values: Vec<(&'static str, i64)>,
+}
+
+impl Parameters {
+    pub fn new(declared: &[Parameter], part: Part, overrides: &[(String, i64)]) -> Parameters {
+        // The last override of a parameter wins, overrides of undeclared parameters are ignored
+        Parameters {
+            values: declared
+                .iter()
+                .map(|parameter| {
+                    let value = overrides
+                        .iter()
+                        .rev()
+                        .find(|(name, _)| name == parameter.name)
+                        .map_or(parameter.default_for(part), |(_, value)| *value);
+                    (parameter.name, value)
+                })
+                .collect(),
+        }
+    }
+
+    pub fn defaults(declared: &[Parameter], part: Part) -> Parameters {
+        Parameters::new(declared, part, &[])
+    }
+
+    pub fn get(&self, name: &str) -> i64 {
+        // Solutions only ask for the parameters they declared
+        self.values
+            .iter()
+            .find(|(parameter_name, _)| *parameter_name == name)
+            .map(|(_, value)| *value)
+            .unwrap_or_else(|| panic!("Parameter '{}' is not declared", name))
+    }
+}
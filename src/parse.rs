@@ -0,0 +1,214 @@
+use std::error::Error;
+use std::fmt;
+use std::str::FromStr;
+
+// Position and content of a malformed part of a puzzle input
+// Line and column numbers start at 1, the column is counted in chars
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub day: u32,
+    pub line: usize,
+    pub column: usize,
+    pub text: String,
+    pub source_line: String,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Show the message together with the input line and a marker below the offending text
+        write!(
+            f,
+            "Day {}, line {}, column {}: {}",
+            self.day, self.line, self.column, self.message
+        )?;
+        if !self.text.is_empty() {
+            write!(f, " '{}'", self.text)?;
+        }
+        let line_number = self.line.to_string();
+        write!(
+            f,
+            "\n {} | {}\n {} | {}{}",
+            line_number,
+            self.source_line,
+            " ".repeat(line_number.len()),
+            " ".repeat(self.column - 1),
+            "^".repeat(usize::max(self.text.chars().count(), 1))
+        )
+    }
+}
+
+impl Error for ParseError {}
+
+// All violations found in a puzzle input
+// The parsers continue after a malformed line, so every problem is reported in a single run
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InvalidInput {
+    pub violations: Vec<ParseError>,
+}
+
+impl InvalidInput {
+    pub fn from_violations(violations: Vec<ParseError>) -> Result<(), InvalidInput> {
+        if violations.is_empty() {
+            Ok(())
+        } else {
+            Err(InvalidInput { violations })
+        }
+    }
+}
+
+impl fmt::Display for InvalidInput {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // A single violation is shown on its own, several are listed below a count
+        if self.violations.len() > 1 {
+            writeln!(f, "{} violations in the input", self.violations.len())?;
+        }
+        for (i, violation) in self.violations.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}", violation)?;
+        }
+        Ok(())
+    }
+}
+
+impl Error for InvalidInput {}
+
+impl From<ParseError> for InvalidInput {
+    fn from(error: ParseError) -> InvalidInput {
+        InvalidInput {
+            violations: vec![error],
+        }
+    }
+}
+
+// Single line of a puzzle input, used to create errors pointing into this line
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct InputLine<'a> {
+    pub day: u32,
+    pub number: usize,
+    pub text: &'a str,
+}
+
+impl<'a> InputLine<'a> {
+    pub fn error(&self, text: &str, message: &str) -> ParseError {
+        // The column is derived from the position of the text within the line
+        // Text that is not a slice of the line is reported at the start of the line
+        let line_start = self.text.as_ptr() as usize;
+        let text_start = text.as_ptr() as usize;
+        let offset = if text_start >= line_start && text_start <= line_start + self.text.len() {
+            text_start - line_start
+        } else {
+            0
+        };
+        ParseError {
+            day: self.day,
+            line: self.number,
+            column: self.text[..offset].chars().count() + 1,
+            text: String::from(text),
+            source_line: String::from(self.text),
+            message: String::from(message),
+        }
+    }
+
+    pub fn end_error(&self, message: &str) -> ParseError {
+        // Error for missing content at the end of the line
+        self.error(&self.text[self.text.len()..], message)
+    }
+
+    pub fn parse<T: FromStr>(&self, text: &str, description: &str) -> Result<T, ParseError> {
+        text.parse::<T>()
+            .map_err(|_| self.error(text, &format!("Invalid {}", description)))
+    }
+
+    pub fn split_once(
+        &self,
+        text: &'a str,
+        separator: &str,
+    ) -> Result<(&'a str, &'a str), ParseError> {
+        text.split_once(separator)
+            .ok_or_else(|| self.error(text, &format!("Missing separator '{}' in", separator)))
+    }
+
+    pub fn digit(&self, index: usize, c: char, radix: u32) -> Result<u32, ParseError> {
+        // Chars of a line are accessed by char index, the text of the char is sliced from the line
+        c.to_digit(radix).ok_or_else(|| {
+            let start = self.text.char_indices().nth(index).map_or(0, |(i, _)| i);
+            self.error(&self.text[start..start + c.len_utf8()], "Invalid digit")
+        })
+    }
+}
+
+pub fn lines(day: u32, input: &str) -> impl Iterator<Item = InputLine<'_>> {
+    input
+        .lines()
+        .enumerate()
+        .map(move |(index, text)| InputLine {
+            day,
+            number: index + 1,
+            text,
+        })
+}
+
+pub fn end_of_input(day: u32, input: &str) -> InputLine<'_> {
+    // Virtual empty line after the last line, used to report missing content
+    InputLine {
+        day,
+        number: input.lines().count() + 1,
+        text: &input[input.len()..],
+    }
+}
+
+pub fn single_line(day: u32, input: &str) -> Result<InputLine<'_>, InvalidInput> {
+    // Input of a single line, further lines would be ignored by the solver
+    // A missing line is the empty line at the end of the input, so its content is reported as missing
+    let mut lines = lines(day, input);
+    let line = lines.next().unwrap_or_else(|| end_of_input(day, input));
+    InvalidInput::from_violations(
+        lines
+            .filter(|line| !line.text.trim().is_empty())
+            .map(|line| line.error(line.text, "Expected a single line of input, found"))
+            .collect(),
+    )?;
+    Ok(line)
+}
+
+pub fn collect_all<T, E: Into<InvalidInput>>(
+    results: impl IntoIterator<Item = Result<T, E>>,
+) -> Result<Vec<T>, InvalidInput> {
+    // Unlike collecting into a Result, the violations of all items are kept instead of only the first
+    let mut values = Vec::new();
+    let mut violations = Vec::new();
+    for result in results {
+        match result {
+            Ok(value) => values.push(value),
+            Err(error) => violations.extend(error.into().violations),
+        }
+    }
+    InvalidInput::from_violations(violations).map(|_| values)
+}
+
+pub fn combine<A, B>(
+    first: Result<A, InvalidInput>,
+    second: Result<B, InvalidInput>,
+) -> Result<(A, B), InvalidInput> {
+    // Join two independently parsed sections of an input with the violations of both
+    match (first, second) {
+        (Ok(first), Ok(second)) => Ok((first, second)),
+        (Err(error), Ok(_)) | (Ok(_), Err(error)) => Err(error),
+        (Err(mut first), Err(second)) => {
+            first.violations.extend(second.violations);
+            Err(first)
+        }
+    }
+}
+
+pub fn parse_lines<'a, T>(
+    day: u32,
+    input: &'a str,
+    parse_line: impl FnMut(InputLine<'a>) -> Result<T, ParseError>,
+) -> Result<Vec<T>, InvalidInput> {
+    // Each line is parsed even if a line before it is malformed
+    collect_all(lines(day, input).map(parse_line))
+}
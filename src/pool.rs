@@ -0,0 +1,57 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+// Apply a function to all items on a pool of worker threads
+// Each worker takes the next unprocessed item until all items are done,
+// the results keep the order of the items independent of the order the workers finish in
+// A panic only fails its own item, the result holds the panic message and the worker continues with the next item
+pub fn map<T: Sync, R: Send>(
+    items: &[T],
+    jobs: usize,
+    f: impl Fn(&T) -> R + Sync,
+) -> Vec<Result<R, String>> {
+    let next_index = AtomicUsize::new(0);
+    let results = Mutex::new(
+        items
+            .iter()
+            .map(|_| None)
+            .collect::<Vec<Option<Result<R, String>>>>(),
+    );
+    thread::scope(|scope| {
+        for _ in 0..jobs.clamp(1, usize::max(1, items.len())) {
+            scope.spawn(|| loop {
+                let index = next_index.fetch_add(1, Ordering::Relaxed);
+                match items.get(index) {
+                    Some(item) => {
+                        let result = panic::catch_unwind(AssertUnwindSafe(|| f(item)))
+                            .map_err(panic_message);
+                        results.lock().unwrap()[index] = Some(result);
+                    }
+                    None => break,
+                }
+            });
+        }
+    });
+    // Panics are caught per item, so every item has a result here
+    results
+        .into_inner()
+        .unwrap()
+        .into_iter()
+        .map(Option::unwrap)
+        .collect()
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    // Panics with a format string carry a String, panics with a literal a &str
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => payload
+            .downcast_ref::<&str>()
+            .map_or(String::from("unknown panic"), |message| {
+                String::from(*message)
+            }),
+    }
+}
@@ -0,0 +1,65 @@
+use crate::days::*;
+use crate::input::{InputError, InputSource};
+use crate::report::PartResult;
+use crate::solution::{Puzzle, SolveError};
+use std::fmt;
+
+pub const DAYS: [&dyn Puzzle; 25] = [
+    &day_1::Day1,
+    &day_2::Day2,
+    &day_3::Day3,
+    &day_4::Day4,
+    &day_5::Day5,
+    &day_6::Day6,
+    &day_7::Day7,
+    &day_8::Day8,
+    &day_9::Day9,
+    &day_10::Day10,
+    &day_11::Day11,
+    &day_12::Day12,
+    &day_13::Day13,
+    &day_14::Day14,
+    &day_15::Day15,
+    &day_16::Day16,
+    &day_17::Day17,
+    &day_18::Day18,
+    &day_19::Day19,
+    &day_20::Day20,
+    &day_21::Day21,
+    &day_22::Day22,
+    &day_23::Day23,
+    &day_24::Day24,
+    &day_25::Day25,
+];
+
+pub fn find_day(number: u32) -> Option<&'static dyn Puzzle> {
+    DAYS.iter().cloned().find(|day| day.day() == number)
+}
+
+#[derive(Debug)]
+pub enum DayBinaryError {
+    Input(InputError),
+    Solve(SolveError),
+}
+
+impl fmt::Display for DayBinaryError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DayBinaryError::Input(error) => write!(f, "{}", error),
+            DayBinaryError::Solve(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+pub fn run_day_binary<I: IntoIterator<Item = String>>(
+    number: u32,
+    args: I,
+) -> Result<Vec<PartResult>, DayBinaryError> {
+    // Solves all parts of a day like `aoc run N` for the per-day binaries, which print the results
+    let day = find_day(number).unwrap_or_else(|| panic!("Day {} is not registered", number));
+    let mut reader = InputSource::from_args(number, args)
+        .and_then(|source| source.open(day.example()))
+        .map_err(DayBinaryError::Input)?;
+    day.solve_reader(&mut reader, day.parts(), &[])
+        .map_err(DayBinaryError::Solve)
+}
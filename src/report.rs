@@ -0,0 +1,115 @@
+use crate::json::Json;
+use crate::solution::{Answer, Part};
+use std::time::Duration;
+
+// Day specific analysis of the input printed below the answers, e.g. the depth profile of day 1
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub title: String,
+    pub text: String,
+    pub data: Json,
+}
+
+// Answer of a single puzzle part together with the time it took to solve it
+#[derive(Debug, Clone)]
+pub struct PartResult {
+    pub day: u32,
+    pub part: Part,
+    pub answer: Answer,
+    pub elapsed: Duration,
+    pub report: Option<Report>,
+}
+
+pub fn format_elapsed(elapsed: Duration) -> String {
+    format!("{:.3} ms", elapsed.as_secs_f64() * 1000.0)
+}
+
+pub fn format_table(results: &[PartResult]) -> String {
+    // Create a summary table with a row per solved part
+    // Multi-line answers continue in the answer column of the following lines
+    let header = ("Day", "Part", "Answer", "Time");
+    let elapsed = results
+        .iter()
+        .map(|result| format_elapsed(result.elapsed))
+        .collect::<Vec<String>>();
+    let answer_width = results
+        .iter()
+        .flat_map(|result| result.answer.value.lines())
+        .map(|line| line.chars().count())
+        .fold(header.2.len(), usize::max);
+    let elapsed_width = elapsed
+        .iter()
+        .map(String::len)
+        .fold(header.3.len(), usize::max);
+
+    let mut table = format!(
+        "{:>3}  {:>4}  {:<answer_width$}  {:>elapsed_width$}\n",
+        header.0,
+        header.1,
+        header.2,
+        header.3,
+        answer_width = answer_width,
+        elapsed_width = elapsed_width
+    );
+    for (result, elapsed) in results.iter().zip(elapsed) {
+        let mut answer_lines = result.answer.value.lines();
+        table.push_str(&format!(
+            "{:>3}  {:>4}  {:<answer_width$}  {:>elapsed_width$}\n",
+            result.day,
+            result.part,
+            answer_lines.next().unwrap_or(""),
+            elapsed,
+            answer_width = answer_width,
+            elapsed_width = elapsed_width
+        ));
+        for answer_line in answer_lines {
+            table.push_str(&format!("{:>3}  {:>4}  {}\n", "", "", answer_line));
+        }
+    }
+    table
+}
+
+pub fn format_json(results: &[PartResult]) -> String {
+    // Create a JSON array with a record per solved part, each record on its own line
+    // Elapsed times are rounded to nanoseconds to avoid floating point noise in the output
+    let records = results
+        .iter()
+        .map(|result| {
+            // The report is only added to the records of parts that have one
+            let record = Json::object()
+                .with("day", result.day)
+                .with("part", result.part.number())
+                .with("answer", result.answer.value.as_str())
+                .with(
+                    "elapsed_ms",
+                    (result.elapsed.as_secs_f64() * 1e9).round() / 1e6,
+                )
+                .with("extra", result.answer.extra.clone());
+            match &result.report {
+                Some(report) => record.with("report", report.data.clone()),
+                None => record,
+            }
+            .to_string()
+        })
+        .collect::<Vec<String>>();
+    if records.is_empty() {
+        String::from("[]\n")
+    } else {
+        format!("[\n  {}\n]\n", records.join(",\n  "))
+    }
+}
+
+pub fn format_reports(results: &[PartResult]) -> String {
+    // Each report follows a heading with its day and part
+    results
+        .iter()
+        .filter_map(|result| {
+            result.report.as_ref().map(|report| {
+                format!(
+                    "\nDay {} part {}: {}\n{}",
+                    result.day, result.part, report.title, report.text
+                )
+            })
+        })
+        .collect()
+}
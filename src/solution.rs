@@ -0,0 +1,427 @@
+use crate::bench::{Phase, PhaseTimings};
+use crate::frames::Frame;
+use crate::generate::Rng;
+use crate::json::Json;
+use crate::log;
+use crate::netpbm::Image;
+use crate::parameters::{Parameter, Parameters};
+use crate::parse::InvalidInput;
+use crate::report::{PartResult, Report};
+use std::fmt;
+use std::hint::black_box;
+use std::io::BufRead;
+use std::time::Instant;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub fn from_number(number: &str) -> Option<Part> {
+        match number {
+            "1" => Some(Part::One),
+            "2" => Some(Part::Two),
+            _ => None,
+        }
+    }
+
+    pub fn number(&self) -> u32 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // Pad to support width and alignment in table layouts
+        match self {
+            Part::One => f.pad("1"),
+            Part::Two => f.pad("2"),
+        }
+    }
+}
+
+// Answer of a puzzle part with additional day specific data, e.g. the position leading to the answer
+#[derive(Debug, Clone, PartialEq)]
+pub struct Answer {
+    pub value: String,
+    pub extra: Json,
+}
+
+impl Answer {
+    pub fn new(value: impl fmt::Display) -> Answer {
+        Answer {
+            value: value.to_string(),
+            extra: Json::object(),
+        }
+    }
+
+    pub fn with_extra(mut self, key: &str, value: impl Into<Json>) -> Answer {
+        self.extra = self.extra.with(key, value);
+        self
+    }
+}
+
+// Reason a day is not solved
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SolveError {
+    Invalid(InvalidInput),
+    // The input is well formed, but a part has no answer for it, e.g. a search that fails with the given parameters
+    Unsolvable {
+        day: u32,
+        part: Part,
+        reason: String,
+    },
+    // Reading the input stream failed after it was opened
+    Unreadable {
+        day: u32,
+        reason: String,
+    },
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            SolveError::Invalid(error) => write!(f, "{}", error),
+            SolveError::Unsolvable { day, part, reason } => {
+                write!(f, "Day {} part {} has no answer: {}", day, part, reason)
+            }
+            SolveError::Unreadable { day, reason } => {
+                write!(f, "Unable to read the input of day {}: {}", day, reason)
+            }
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+impl From<InvalidInput> for SolveError {
+    fn from(error: InvalidInput) -> SolveError {
+        SolveError::Invalid(error)
+    }
+}
+
+// Common shape of all puzzle days:
+// The input text is parsed once and both puzzle parts are solved on the parsed input
+// Malformed input is reported by the parser, the parts can rely on a well formed input
+// A part that finds no answer for a well formed input returns the reason instead of a partial answer
+// The parser checks the whole input and reports all violations instead of stopping at the first
+pub trait Solution {
+    const DAY: u32;
+    const TITLE: &'static str;
+    const EXAMPLE: &'static str;
+
+    // Parts of the puzzle, the last day has only one
+    const PARTS: &'static [Part] = &[Part::One, Part::Two];
+
+    // Knobs of the puzzle that can be overridden from the command line, none by default
+    const PARAMETERS: &'static [Parameter] = &[];
+
+    type Input;
+
+    fn parse(input: &str) -> Result<Self::Input, InvalidInput>;
+
+    // Random well formed input, the size scales the input in a day specific way
+    fn generate(rng: &mut Rng, size: usize) -> String;
+
+    fn part_one(input: &Self::Input, parameters: &Parameters) -> Result<Answer, String>;
+
+    fn part_two(input: &Self::Input, parameters: &Parameters) -> Result<Answer, String>;
+
+    fn solve(input: &Self::Input, part: Part, parameters: &Parameters) -> Result<Answer, String> {
+        match part {
+            Part::One => Self::part_one(input, parameters),
+            Part::Two => Self::part_two(input, parameters),
+        }
+    }
+
+    // Image of the state a part ends in, for days with a 2d state that is hard to inspect as text
+    // The state is created again, so the solvers are not slowed down by images nobody asked for
+    fn image(_input: &Self::Input, _part: Part, _parameters: &Parameters) -> Option<Image> {
+        None
+    }
+
+    // Snapshots of every step of a simulation day, starting with the initial state
+    // Frames are passed on one by one, so long simulations do not keep all states in memory
+    fn frames(
+        _input: &Self::Input,
+        _part: Part,
+        _parameters: &Parameters,
+        _emit: &mut dyn FnMut(Frame),
+    ) {
+    }
+
+    // Analysis of the input printed below the answers, for days with more to tell than the answer
+    // It is created after the timed solver, so it does not count towards the time of the part
+    fn report(_input: &Self::Input, _part: Part, _parameters: &Parameters) -> Option<Report> {
+        None
+    }
+
+    // Table of the state after every step as CSV with a header row, for days whose course is worth auditing
+    fn trace(_input: &Self::Input, _part: Part, _parameters: &Parameters) -> Option<String> {
+        None
+    }
+
+    // Solve the requested parts on an input stream, the whole input is read first by default
+    // Days that solve both parts in a single pass over the lines can stream inputs larger than the memory
+    fn solve_reader(
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+    ) -> Result<Vec<PartResult>, SolveError>
+    where
+        Self: Sized,
+    {
+        solve_read_input::<Self>(reader, parts, overrides)
+    }
+}
+
+pub fn solve_input<S: Solution>(
+    input: &str,
+    parts: &[Part],
+    overrides: &[(String, i64)],
+) -> Result<Vec<PartResult>, SolveError> {
+    let input = S::parse(input)?;
+    parts
+        .iter()
+        .map(|part| {
+            // Log messages of the solvers are labeled with the day and part
+            let parameters = Parameters::new(S::PARAMETERS, *part, overrides);
+            log::with_scope(format!("day {} part {}", S::DAY, part), || {
+                let start = Instant::now();
+                let answer = S::solve(&input, *part, &parameters);
+                let elapsed = start.elapsed();
+                let answer = answer.map_err(|reason| SolveError::Unsolvable {
+                    day: S::DAY,
+                    part: *part,
+                    reason,
+                })?;
+                Ok(PartResult {
+                    day: S::DAY,
+                    part: *part,
+                    answer,
+                    elapsed,
+                    report: S::report(&input, *part, &parameters),
+                })
+            })
+        })
+        .collect()
+}
+
+pub fn solve_read_input<S: Solution>(
+    reader: &mut dyn BufRead,
+    parts: &[Part],
+    overrides: &[(String, i64)],
+) -> Result<Vec<PartResult>, SolveError> {
+    let mut input = String::new();
+    reader
+        .read_to_string(&mut input)
+        .map_err(|error| SolveError::Unreadable {
+            day: S::DAY,
+            reason: error.to_string(),
+        })?;
+    solve_input::<S>(&input, parts, overrides)
+}
+
+// Object safe view on a solution to keep the solutions of all days in a single registry
+pub trait Puzzle: Sync {
+    fn day(&self) -> u32;
+
+    fn title(&self) -> &'static str;
+
+    fn example(&self) -> &'static str;
+
+    fn parameters(&self) -> &'static [Parameter];
+
+    fn parts(&self) -> &'static [Part];
+
+    // Synthetic input of the given size, the same seed always creates the same input
+    fn generate(&self, size: usize, seed: u64) -> String;
+
+    // Check the input without solving any part
+    fn validate(&self, input: &str) -> Result<(), InvalidInput>;
+
+    // Parse the input once and solve the requested parts with the time spent on each part
+    // Overrides replace the defaults of declared parameters by name
+    fn solve_parts(
+        &self,
+        input: &str,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+    ) -> Result<Vec<PartResult>, SolveError>;
+
+    // Same as solve_parts with the input read from a stream, see Solution::solve_reader
+    fn solve_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+    ) -> Result<Vec<PartResult>, SolveError>;
+
+    // Image of the final state of a part, None if the day has no image
+    fn render_image(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &[(String, i64)],
+    ) -> Result<Option<Image>, InvalidInput>;
+
+    // Frames of all steps of a part, nothing is emitted if the day has no frames
+    fn export_frames(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &[(String, i64)],
+        emit: &mut dyn FnMut(Frame),
+    ) -> Result<(), InvalidInput>;
+
+    // CSV trace of all steps of a part, None if the day has no trace
+    fn export_trace(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &[(String, i64)],
+    ) -> Result<Option<String>, InvalidInput>;
+
+    // Time parsing and each of the requested parts separately over the given number of runs
+    fn bench_parts(
+        &self,
+        input: &str,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+        runs: usize,
+    ) -> Result<Vec<PhaseTimings>, InvalidInput>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u32 {
+        S::DAY
+    }
+
+    fn title(&self) -> &'static str {
+        S::TITLE
+    }
+
+    fn example(&self) -> &'static str {
+        S::EXAMPLE
+    }
+
+    fn parameters(&self) -> &'static [Parameter] {
+        S::PARAMETERS
+    }
+
+    fn parts(&self) -> &'static [Part] {
+        S::PARTS
+    }
+
+    fn generate(&self, size: usize, seed: u64) -> String {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn validate(&self, input: &str) -> Result<(), InvalidInput> {
+        S::parse(input).map(|_| ())
+    }
+
+    fn solve_parts(
+        &self,
+        input: &str,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+    ) -> Result<Vec<PartResult>, SolveError> {
+        solve_input::<S>(input, parts, overrides)
+    }
+
+    fn solve_reader(
+        &self,
+        reader: &mut dyn BufRead,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+    ) -> Result<Vec<PartResult>, SolveError> {
+        S::solve_reader(reader, parts, overrides)
+    }
+
+    fn render_image(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &[(String, i64)],
+    ) -> Result<Option<Image>, InvalidInput> {
+        let input = S::parse(input)?;
+        let parameters = Parameters::new(S::PARAMETERS, part, overrides);
+        Ok(log::with_scope(
+            format!("day {} part {}", S::DAY, part),
+            || S::image(&input, part, &parameters),
+        ))
+    }
+
+    fn export_frames(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &[(String, i64)],
+        emit: &mut dyn FnMut(Frame),
+    ) -> Result<(), InvalidInput> {
+        let input = S::parse(input)?;
+        let parameters = Parameters::new(S::PARAMETERS, part, overrides);
+        log::with_scope(format!("day {} part {}", S::DAY, part), || {
+            S::frames(&input, part, &parameters, emit)
+        });
+        Ok(())
+    }
+
+    fn export_trace(
+        &self,
+        input: &str,
+        part: Part,
+        overrides: &[(String, i64)],
+    ) -> Result<Option<String>, InvalidInput> {
+        let input = S::parse(input)?;
+        let parameters = Parameters::new(S::PARAMETERS, part, overrides);
+        Ok(log::with_scope(
+            format!("day {} part {}", S::DAY, part),
+            || S::trace(&input, part, &parameters),
+        ))
+    }
+
+    fn bench_parts(
+        &self,
+        input: &str,
+        parts: &[Part],
+        overrides: &[(String, i64)],
+        runs: usize,
+    ) -> Result<Vec<PhaseTimings>, InvalidInput> {
+        let time = |phase: Phase, run: &dyn Fn()| PhaseTimings {
+            day: S::DAY,
+            phase,
+            samples: (0..runs)
+                .map(|_| {
+                    let start = Instant::now();
+                    run();
+                    start.elapsed()
+                })
+                .collect(),
+        };
+
+        // The parts are timed on a single parsed input, the answers and failures are discarded
+        let parsed_input = S::parse(input)?;
+        let mut timings = vec![time(Phase::Parse, &|| {
+            let _ = black_box(S::parse(input));
+        })];
+        for part in parts {
+            let parameters = Parameters::new(S::PARAMETERS, *part, overrides);
+            timings.push(log::with_scope(
+                format!("day {} part {}", S::DAY, part),
+                || {
+                    time(Phase::Solve(*part), &|| {
+                        let _ = black_box(S::solve(&parsed_input, *part, &parameters));
+                    })
+                },
+            ));
+        }
+        Ok(timings)
+    }
+}
@@ -0,0 +1,211 @@
+use crate::input::InputSource;
+use crate::registry;
+use crate::solution::Part;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+// Recorded answer of a puzzle part for a specific input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    pub day: u32,
+    pub part: Part,
+    pub input: InputSource,
+    pub expected: String,
+    // Personal inputs below inputs/ are not part of the repository, a missing file is skipped
+    pub personal: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch(String),
+    Skipped(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct Verification {
+    pub answer: ExpectedAnswer,
+    pub outcome: Outcome,
+}
+
+fn parse_string_value(value: &str) -> Option<String> {
+    // Parse a basic TOML string with the escape sequences \n, \t, \" and \\
+    let content = value.strip_prefix('"')?.strip_suffix('"')?;
+    let mut string = String::new();
+    let mut chars = content.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => match chars.next()? {
+                'n' => string.push('\n'),
+                't' => string.push('\t'),
+                '"' => string.push('"'),
+                '\\' => string.push('\\'),
+                _ => return None,
+            },
+            '"' => return None,
+            c => string.push(c),
+        }
+    }
+    Some(string)
+}
+
+pub fn parse_answers(text: &str, base_directory: &Path) -> Result<Vec<ExpectedAnswer>, String> {
+    // Parse the [[answer]] tables of an answers file
+    // Each table needs a day, a part, an input ("example" or a file path) and the expected answer
+    // Input file paths are relative to the directory of the answers file
+    fn finish_table(
+        table: Option<(usize, Vec<(String, String)>)>,
+        base_directory: &Path,
+    ) -> Result<Option<ExpectedAnswer>, String> {
+        let (line_number, entries) = match table {
+            Some(table) => table,
+            None => return Ok(None),
+        };
+        let get = |key: &str| {
+            entries
+                .iter()
+                .find(|(k, _)| k == key)
+                .map(|(_, value)| value.as_str())
+                .ok_or_else(|| format!("Line {}: answer is missing '{}'", line_number, key))
+        };
+        let day = get("day")?
+            .parse::<u32>()
+            .map_err(|_| format!("Line {}: day has to be a number", line_number))?;
+        let part = Part::from_number(get("part")?)
+            .ok_or_else(|| format!("Line {}: part has to be 1 or 2", line_number))?;
+        let input_path = get("input")?;
+        let input = match input_path {
+            "example" => InputSource::Example,
+            path => InputSource::File(base_directory.join(path)),
+        };
+        Ok(Some(ExpectedAnswer {
+            day,
+            part,
+            input,
+            expected: String::from(get("expected")?),
+            personal: Path::new(input_path).starts_with("inputs"),
+        }))
+    }
+
+    let mut answers = Vec::new();
+    let mut table: Option<(usize, Vec<(String, String)>)> = None;
+    for (index, line) in text.lines().enumerate() {
+        let line_number = index + 1;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        if line == "[[answer]]" {
+            answers.extend(finish_table(table.take(), base_directory)?);
+            table = Some((line_number, Vec::new()));
+            continue;
+        }
+        let (key, value) = line
+            .split_once('=')
+            .map(|(key, value)| (key.trim(), value.trim()))
+            .ok_or_else(|| format!("Line {}: expected 'key = value'", line_number))?;
+        let value = if value.starts_with('"') {
+            parse_string_value(value)
+                .ok_or_else(|| format!("Line {}: invalid string {}", line_number, value))?
+        } else {
+            String::from(value)
+        };
+        match table.as_mut() {
+            Some((_, entries)) => entries.push((String::from(key), value)),
+            None => return Err(format!("Line {}: value outside of [[answer]]", line_number)),
+        }
+    }
+    answers.extend(finish_table(table, base_directory)?);
+    Ok(answers)
+}
+
+pub fn load_answers(path: &Path) -> Result<Vec<ExpectedAnswer>, String> {
+    let text = fs::read_to_string(path)
+        .map_err(|error| format!("Unable to read {}: {}", path.display(), error))?;
+    let base_directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+    parse_answers(&text, &base_directory).map_err(|error| format!("{}: {}", path.display(), error))
+}
+
+pub fn default_answers_path() -> PathBuf {
+    PathBuf::from("answers.toml")
+}
+
+pub fn verify(answers: &[ExpectedAnswer]) -> Vec<Verification> {
+    // Solve every recorded answer and compare the result with the expectation
+    // Answers with a missing personal input file are skipped, any other unreadable input is a mismatch
+    answers
+        .iter()
+        .map(|answer| {
+            let outcome = match registry::find_day(answer.day) {
+                None => Outcome::Skipped(format!("day {} is not solved", answer.day)),
+                Some(puzzle) => match answer.input.read(puzzle.example()) {
+                    Err(error) if answer.personal => Outcome::Skipped(error.to_string()),
+                    Err(error) => Outcome::Mismatch(error.to_string()),
+                    Ok(input) => match puzzle.solve_parts(&input, &[answer.part], &[]) {
+                        Ok(mut results) => {
+                            let actual = results.remove(0).answer;
+                            if actual.value == answer.expected {
+                                Outcome::Match
+                            } else {
+                                Outcome::Mismatch(actual.value)
+                            }
+                        }
+                        Err(error) => Outcome::Mismatch(error.to_string()),
+                    },
+                },
+            };
+            Verification {
+                answer: answer.clone(),
+                outcome,
+            }
+        })
+        .collect()
+}
+
+pub fn format_diff(expected: &str, actual: &str) -> String {
+    // Line based comparison, matching lines are indented and differing lines marked with -/+
+    let expected_lines = expected.lines().collect::<Vec<&str>>();
+    let actual_lines = actual.lines().collect::<Vec<&str>>();
+    let mut diff = String::new();
+    for i in 0..usize::max(expected_lines.len(), actual_lines.len()) {
+        match (expected_lines.get(i), actual_lines.get(i)) {
+            (Some(e), Some(a)) if e == a => diff.push_str(&format!("    {}\n", e)),
+            (e, a) => {
+                if let Some(e) = e {
+                    diff.push_str(&format!("  - {}\n", e));
+                }
+                if let Some(a) = a {
+                    diff.push_str(&format!("  + {}\n", a));
+                }
+            }
+        }
+    }
+    diff
+}
+
+pub fn format_report(verifications: &[Verification]) -> String {
+    // List every mismatch with a diff and every skipped answer, followed by a summary line
+    let mut report = String::new();
+    let mut counts = (0, 0, 0);
+    for verification in verifications {
+        let answer = &verification.answer;
+        let label = format!("Day {} part {} ({})", answer.day, answer.part, answer.input);
+        match &verification.outcome {
+            Outcome::Match => counts.0 += 1,
+            Outcome::Mismatch(actual) => {
+                counts.1 += 1;
+                report.push_str(&format!("{}: mismatch\n", label));
+                report.push_str(&format_diff(&answer.expected, actual));
+            }
+            Outcome::Skipped(reason) => {
+                counts.2 += 1;
+                report.push_str(&format!("{}: skipped, {}\n", label, reason));
+            }
+        }
+    }
+    report.push_str(&format!(
+        "{} matched, {} mismatched, {} skipped\n",
+        counts.0, counts.1, counts.2
+    ));
+    report
+}
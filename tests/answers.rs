@@ -0,0 +1,16 @@
+use aoc2021::verify::{self, Outcome};
+use std::path::Path;
+
+#[test]
+fn recorded_answers_match() {
+    let path = Path::new(concat!(env!("CARGO_MANIFEST_DIR"), "/answers.toml"));
+    let answers = verify::load_answers(path).unwrap();
+    let verifications = verify::verify(&answers);
+    assert!(
+        verifications
+            .iter()
+            .all(|verification| !matches!(verification.outcome, Outcome::Mismatch(_))),
+        "\n{}",
+        verify::format_report(&verifications)
+    );
+}
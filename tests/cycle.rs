@@ -0,0 +1,24 @@
+use aoc2021::cycle::CycleDetector;
+
+fn first_cycle(start: u32, next: impl Fn(u32) -> u32) -> (u32, usize) {
+    // The step at which the repetition is noticed and the length of the cycle
+    let mut detector = CycleDetector::new(&start);
+    let mut state = start;
+    for step in 1.. {
+        state = next(state);
+        if let Some(length) = detector.cycle_length(&state) {
+            return (step, length);
+        }
+    }
+    unreachable!()
+}
+
+#[test]
+fn cycles_are_found_with_their_length() {
+    assert_eq!(first_cycle(0, |state| state).1, 1);
+    assert_eq!(first_cycle(0, |state| (state + 1) % 7).1, 7);
+    // A tail of 10 states before a cycle of 3 states
+    let (step, length) = first_cycle(0, |state| if state < 12 { state + 1 } else { 10 });
+    assert_eq!(length, 3);
+    assert!(step <= 2 * (10 + 3));
+}
@@ -0,0 +1,125 @@
+use aoc2021::days::day_1::{self, Day1, Run, SweepError};
+use aoc2021::report::PartResult;
+use aoc2021::{Part, Puzzle, Solution, SolveError};
+use std::collections::BTreeMap;
+
+fn increasing_window_sums(numbers: &[i32], window_size: usize) -> u64 {
+    // Sums of all windows compared directly, the definition the sweep has to agree with
+    let sums = numbers
+        .windows(window_size)
+        .map(|window| window.iter().map(|depth| *depth as i64).sum())
+        .collect::<Vec<i64>>();
+    sums.windows(2).filter(|pair| pair[1] > pair[0]).count() as u64
+}
+
+fn answers(results: Vec<PartResult>) -> Vec<String> {
+    results
+        .into_iter()
+        .map(|result| result.answer.value)
+        .collect()
+}
+
+#[test]
+fn sweep_matches_window_sums() {
+    for seed in 0..5 {
+        let input = Day1.generate(500, seed);
+        let numbers = Day1::parse(&input).unwrap();
+        for window_size in [1, 2, 3, 4, 7, 499, 500, 501] {
+            let sweep = day_1::sweep_reader(input.as_bytes(), window_size).unwrap();
+            assert_eq!(sweep.measurements(), numbers.len() as u64);
+            assert_eq!(
+                sweep.increases(),
+                increasing_window_sums(&numbers, window_size),
+                "seed {}, window size {}",
+                seed,
+                window_size
+            );
+        }
+    }
+}
+
+#[test]
+fn streamed_parts_match_parsed_parts() {
+    let input = Day1.generate(1000, 7);
+    let parts = [Part::One, Part::Two];
+    for overrides in [vec![], vec![(String::from("window"), 5)]] {
+        let parsed = Day1.solve_parts(&input, &parts, &overrides).unwrap();
+        let streamed = Day1
+            .solve_reader(&mut input.as_bytes(), &parts, &overrides)
+            .unwrap();
+        assert_eq!(answers(streamed), answers(parsed));
+    }
+}
+
+#[test]
+fn sweep_stops_at_the_first_hundred_violations() {
+    let input = "x\n".repeat(1000);
+    match day_1::sweep_reader(input.as_bytes(), 1) {
+        Err(SweepError::Invalid(error)) => assert_eq!(error.violations.len(), 100),
+        result => panic!("Expected an invalid input instead of {:?}", result),
+    }
+}
+
+#[test]
+fn unreadable_stream_fails_the_day() {
+    // A line that is not valid UTF-8 can not be read as text
+    let input: &[u8] = b"199\n\xff\n200\n";
+    assert!(matches!(
+        day_1::sweep_reader(input, 1),
+        Err(SweepError::Unreadable(_))
+    ));
+    assert!(matches!(
+        Day1.solve_reader(&mut &input[..], &[Part::One], &[]),
+        Err(SolveError::Unreadable { day: 1, .. })
+    ));
+}
+
+#[test]
+fn depth_profile_of_plateaus() {
+    let numbers = [100, 100, 101, 99, 99, 102, 98, 98, 98, 105];
+    let profile = day_1::depth_profile(&numbers, 3);
+    assert_eq!(
+        profile.longest_increasing_run,
+        Some(Run { start: 1, end: 2 })
+    );
+    assert_eq!(
+        profile.longest_decreasing_run,
+        Some(Run { start: 2, end: 3 })
+    );
+    assert_eq!(profile.largest_jump, Some((9, 7)));
+    assert_eq!((profile.plateaus, profile.flat_steps), (3, 4));
+    assert_eq!(
+        profile.delta_histogram,
+        BTreeMap::from([(-4, 1), (-2, 1), (0, 4), (1, 1), (3, 1), (7, 1)])
+    );
+    assert_eq!(profile.increases_per_window, vec![(1, 3), (2, 3), (3, 2)]);
+}
+
+#[test]
+fn depth_profile_without_measurements() {
+    let profile = day_1::depth_profile(&[], 2);
+    assert_eq!(profile.longest_increasing_run, None);
+    assert_eq!(profile.largest_jump, None);
+    assert_eq!(profile.increases_per_window, vec![(1, 0), (2, 0)]);
+}
+
+#[test]
+fn depth_profile_is_reported_for_part_one() {
+    let overrides = [(String::from("profile"), 3)];
+    let numbers = Day1::parse(Day1::EXAMPLE).unwrap();
+    let expected = day_1::depth_profile(&numbers, 3).to_string();
+    let parts = [Part::One, Part::Two];
+    let parsed = Day1.solve_parts(Day1::EXAMPLE, &parts, &overrides).unwrap();
+    // The profile needs all measurements, so the streamed input is read completely for it
+    let streamed = Day1
+        .solve_reader(&mut Day1::EXAMPLE.as_bytes(), &parts, &overrides)
+        .unwrap();
+    for results in [parsed, streamed] {
+        let report = results[0].report.as_ref().unwrap();
+        assert_eq!(report.title, "Depth profile");
+        assert_eq!(report.text, expected);
+        assert_eq!(results[1].report, None);
+    }
+    let results = Day1.solve_parts(Day1::EXAMPLE, &parts, &[]).unwrap();
+    assert!(results.iter().all(|result| result.report.is_none()));
+}
@@ -0,0 +1,19 @@
+use aoc2021::days::day_16::Day16;
+use aoc2021::{Part, Puzzle};
+
+#[test]
+fn leading_zero_groups_do_not_count_towards_the_literal_size() {
+    // Twenty zero groups before a 1 pad the literal to 84 bits, but its value fits
+    let results = Day16
+        .solve_parts("D210842108421084210842108402", &[Part::Two], &[])
+        .unwrap();
+    assert_eq!(results[0].answer.value, "1");
+}
+
+#[test]
+fn overflowing_sum_has_no_answer() {
+    // The sum of two literals of 2^62 exceeds the range of i64
+    let input = "C200B4A4210842108421084200D290842108421084210800";
+    assert!(Day16.solve_parts(input, &[Part::One], &[]).is_ok());
+    assert!(Day16.solve_parts(input, &[Part::Two], &[]).is_err());
+}
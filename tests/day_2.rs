@@ -0,0 +1,183 @@
+use aoc2021::days::day_2::{
+    self, AimedMovement, Command, CommandError, Day2, DirectMovement, MovementModel, OutOfRange,
+};
+use aoc2021::{Part, Puzzle, Solution};
+
+// Moves the submarine twice as far as the direct movement, to run a model from outside the crate
+struct DoubledMovement;
+
+impl MovementModel for DoubledMovement {
+    type State = (i64, i64);
+
+    fn apply(&self, state: &(i64, i64), command: Command) -> Option<(i64, i64)> {
+        let (x, y) = DirectMovement.apply(state, command)?;
+        DirectMovement.apply(&(x, y), command)
+    }
+
+    fn position(&self, state: &(i64, i64)) -> (i64, i64) {
+        *state
+    }
+}
+
+#[test]
+fn commands_round_trip() {
+    for (text, command) in [
+        ("forward 5", Command::Forward(5)),
+        ("up 0", Command::Up(0)),
+        ("down 2147483647", Command::Down(i32::MAX)),
+    ] {
+        assert_eq!(text.parse::<Command>(), Ok(command));
+        assert_eq!(command.to_string(), text);
+    }
+}
+
+#[test]
+fn malformed_commands_are_rejected() {
+    for (text, error) in [
+        ("", CommandError::Empty),
+        ("  ", CommandError::Empty),
+        (
+            "sideways 5",
+            CommandError::UnknownDirection(String::from("sideways")),
+        ),
+        ("up", CommandError::MissingUnits),
+        ("down x", CommandError::InvalidUnits(String::from("x"))),
+        (
+            "forward 2.5",
+            CommandError::InvalidUnits(String::from("2.5")),
+        ),
+        ("forward +4", CommandError::SignedUnits(String::from("+4"))),
+        ("down -3", CommandError::NegativeUnits(String::from("-3"))),
+        (
+            "up 3 extra words",
+            CommandError::ExtraTokens(String::from("extra words")),
+        ),
+    ] {
+        assert_eq!(text.parse::<Command>(), Err(error), "'{}'", text);
+    }
+}
+
+#[test]
+fn violations_point_to_the_offending_token() {
+    let input = "forward 5\n\nsideways 5\nup\ndown x\n  forward   +4\ndown -3\nup 3 extra words";
+    let violations = Day2::parse(input).unwrap_err().violations;
+    let positions = violations
+        .iter()
+        .map(|violation| (violation.line, violation.column, violation.text.as_str()))
+        .collect::<Vec<(usize, usize, &str)>>();
+    assert_eq!(
+        positions,
+        vec![
+            (2, 1, ""),
+            (3, 1, "sideways"),
+            (4, 3, ""),
+            (5, 6, "x"),
+            (6, 13, "+4"),
+            (7, 6, "-3"),
+            (8, 6, "extra words"),
+        ]
+    );
+    assert_eq!(
+        violations[0].message,
+        "Expected a command, found an empty line"
+    );
+    assert_eq!(
+        violations[4].message,
+        "Expected a number of units without a + sign instead of"
+    );
+}
+
+#[test]
+fn models_move_the_submarine() {
+    let commands = Day2::parse(Day2::EXAMPLE).unwrap();
+    assert_eq!(
+        day_2::execute_submarine_movements(&DirectMovement, &commands),
+        Ok((15, 10))
+    );
+    assert_eq!(
+        day_2::execute_submarine_movements(&AimedMovement, &commands),
+        Ok((15, 60))
+    );
+    assert_eq!(
+        day_2::execute_submarine_movements(&DoubledMovement, &commands),
+        Ok((30, 20))
+    );
+}
+
+#[test]
+fn overflowing_course_is_out_of_range() {
+    // The depth of the aimed movement exceeds i64 with the third forward command
+    let input = "down 2147483647\nforward 2147483647\nforward 2147483647\nforward 2147483647";
+    let commands = input
+        .lines()
+        .map(|line| line.parse::<Command>().unwrap())
+        .collect::<Vec<Command>>();
+    assert_eq!(
+        day_2::execute_submarine_movements(&AimedMovement, &commands),
+        Err(OutOfRange { command: 4 })
+    );
+    assert!(day_2::execute_submarine_movements(&DirectMovement, &commands).is_ok());
+    let violations = Day2::parse(input).unwrap_err().violations;
+    assert_eq!(violations.len(), 1);
+    assert_eq!(violations[0].line, 4);
+    assert_eq!(
+        violations[0].message,
+        "Position of the submarine overflows with command"
+    );
+}
+
+#[test]
+fn trace_of_the_aimed_course() {
+    let commands = Day2::parse(Day2::EXAMPLE).unwrap();
+    let trace = day_2::trace_course(&AimedMovement, &commands).unwrap();
+    let csv = day_2::trace_csv(&trace);
+    assert_eq!(
+        csv,
+        "step,command,horizontal_position,depth,aim
+0,,0,0,0
+1,forward 5,5,0,0
+2,down 5,5,0,5
+3,forward 8,13,40,5
+4,up 3,13,40,2
+5,down 8,13,40,10
+6,forward 2,15,60,10
+"
+    );
+    assert_eq!(
+        Day2.export_trace(Day2::EXAMPLE, Part::Two, &[]).unwrap(),
+        Some(csv)
+    );
+}
+
+#[test]
+fn trace_of_the_direct_course_has_no_aim() {
+    let commands = Day2::parse(Day2::EXAMPLE).unwrap();
+    let trace = day_2::trace_course(&DirectMovement, &commands).unwrap();
+    assert_eq!(trace.len(), commands.len() + 1);
+    assert!(trace.iter().all(|step| step.aim.is_none()));
+    assert_eq!(
+        day_2::trace_csv(&trace).lines().nth(4),
+        Some("3,forward 8,13,5,")
+    );
+}
+
+#[test]
+fn summaries_of_the_example_courses() {
+    let commands = Day2::parse(Day2::EXAMPLE).unwrap();
+    let direct = day_2::summarize_course(&DirectMovement, &commands).unwrap();
+    assert_eq!((direct.max_depth, direct.max_depth_step), (10, 5));
+    assert_eq!(direct.total_distance, 31.0);
+    // Moving forward with an aim goes down diagonally
+    let aimed = day_2::summarize_course(&AimedMovement, &commands).unwrap();
+    assert_eq!((aimed.max_depth, aimed.max_depth_step), (60, 6));
+    let expected_distance = 5.0 + 8f64.hypot(40.0) + 2f64.hypot(20.0);
+    assert!((aimed.total_distance - expected_distance).abs() < 1e-9);
+}
+
+#[test]
+fn summary_of_a_course_above_the_surface() {
+    let commands = Day2::parse("up 5\nforward 3").unwrap();
+    let summary = day_2::summarize_course(&DirectMovement, &commands).unwrap();
+    assert_eq!((summary.max_depth, summary.max_depth_step), (0, 0));
+    assert_eq!(summary.total_distance, 8.0);
+}
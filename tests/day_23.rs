@@ -0,0 +1,15 @@
+use aoc2021::days::day_23::Day23;
+use aoc2021::{Part, Puzzle};
+
+#[test]
+fn generated_burrows_can_be_organized() {
+    // Both the burrow and its unfolded variant of part 2 have an answer
+    for size in 1..=3 {
+        for seed in 0..2 {
+            let input = Day23.generate(size, seed);
+            if let Err(error) = Day23.solve_parts(&input, &[Part::One, Part::Two], &[]) {
+                panic!("size {}, seed {}\n{}\n{}", size, seed, input, error);
+            }
+        }
+    }
+}
@@ -0,0 +1,100 @@
+use aoc2021::geometry::{Aabb, Rotation, Vec3};
+use std::collections::HashSet;
+
+fn axes(rotation: Rotation) -> [Vec3; 3] {
+    [
+        Vec3::new(1, 0, 0).rotate(rotation),
+        Vec3::new(0, 1, 0).rotate(rotation),
+        Vec3::new(0, 0, 1).rotate(rotation),
+    ]
+}
+
+fn find_rotation(axes_after: [Vec3; 3]) -> Option<Rotation> {
+    Rotation::all().find(|rotation| axes(*rotation) == axes_after)
+}
+
+#[test]
+fn vector_arithmetic_and_norms() {
+    let a = Vec3::new(1, -2, 3);
+    let b = Vec3::new(-4, 5, 6);
+    assert_eq!(a + b, Vec3::new(-3, 3, 9));
+    assert_eq!(a - b, Vec3::new(5, -7, -3));
+    assert_eq!(-a * 2, Vec3::new(-2, 4, -6));
+    assert_eq!(a.manhattan_norm(), 6);
+    assert_eq!(b.chebyshev_norm(), 6);
+    assert_eq!(a.squared_norm(), 14);
+    assert_eq!(a.manhattan_distance(b), 15);
+    assert_eq!(a.component_min(b), Vec3::new(-4, -2, 3));
+    assert_eq!(a.component_max(b), Vec3::new(1, 5, 6));
+    assert_eq!(a.to_string(), "(1, -2, 3)");
+}
+
+#[test]
+fn rotations_are_the_24_proper_rotations() {
+    let vector = Vec3::new(1, 2, 3);
+    let images = Rotation::all()
+        .map(|rotation| vector.rotate(rotation))
+        .collect::<HashSet<Vec3>>();
+    assert_eq!(images.len(), 24);
+    assert_eq!(vector.rotate(Rotation::IDENTITY), vector);
+    for rotation in Rotation::all() {
+        // The rotated axes keep their length and stay a right-handed system
+        let [x, y, z] = axes(rotation);
+        assert!([x, y, z].iter().all(|axis| axis.squared_norm() == 1));
+        let cross = Vec3::new(
+            y.y * z.z - y.z * z.y,
+            y.z * z.x - y.x * z.z,
+            y.x * z.y - y.y * z.x,
+        );
+        assert_eq!(x.x * cross.x + x.y * cross.y + x.z * cross.z, 1);
+    }
+}
+
+#[test]
+fn rotations_are_closed_under_composition() {
+    for first in Rotation::all() {
+        for second in Rotation::all() {
+            let composed = axes(first).map(|axis| axis.rotate(second));
+            assert!(find_rotation(composed).is_some());
+        }
+        // Every rotation can be undone by another rotation
+        assert!(Rotation::all().any(|inverse| {
+            axes(first).map(|axis| axis.rotate(inverse)) == axes(Rotation::IDENTITY)
+        }));
+    }
+}
+
+#[test]
+fn boxes_include_both_corners() {
+    let cuboid = Aabb::new(Vec3::new(2, 0, 5), Vec3::new(0, 1, 3));
+    assert_eq!(cuboid, Aabb::from_ranges(0..=2, 0..=1, 3..=5));
+    assert_eq!(cuboid.volume(), Some(18));
+    assert!(cuboid.contains(Vec3::new(0, 0, 3)));
+    assert!(cuboid.contains(Vec3::new(2, 1, 5)));
+    assert!(!cuboid.contains(Vec3::new(3, 1, 5)));
+    assert!(cuboid.contains_box(&Aabb::from_ranges(1..=2, 0..=0, 4..=5)));
+    assert!(!cuboid.contains_box(&Aabb::from_ranges(1..=3, 0..=0, 4..=5)));
+    assert_eq!(Aabb::new(Vec3::ZERO, Vec3::ZERO).volume(), Some(1));
+    let huge = Aabb::from_ranges(0..=3_000_000, 0..=3_000_000, 0..=3_000_000);
+    assert_eq!(huge.volume(), None);
+}
+
+#[test]
+fn intersections_of_boxes() {
+    let a = Aabb::from_ranges(0..=2, 0..=2, 0..=2);
+    // Boxes sharing a face intersect in that face
+    let touching = Aabb::from_ranges(2..=4, 1..=3, 0..=2);
+    assert_eq!(
+        a.intersection(&touching),
+        Some(Aabb::from_ranges(2..=2, 1..=2, 0..=2))
+    );
+    assert_eq!(
+        a.intersection(&Aabb::from_ranges(3..=4, 0..=2, 0..=2)),
+        None
+    );
+    assert_eq!(a.intersection(&a), Some(a));
+    assert_eq!(
+        a.bounding_box(&touching),
+        Aabb::from_ranges(0..=4, 0..=3, 0..=2)
+    );
+}
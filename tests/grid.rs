@@ -0,0 +1,81 @@
+use aoc2021::grid::Grid;
+
+fn numbered_grid() -> Grid<usize> {
+    // 3x2 grid with the row-major index as cell
+    Grid::from_fn(3, 2, |(x, y)| y * 3 + x)
+}
+
+#[test]
+fn cells_are_stored_row_by_row() {
+    let grid = numbered_grid();
+    assert_eq!((grid.width(), grid.height()), (3, 2));
+    assert_eq!(grid[(2, 0)], 2);
+    assert_eq!(grid[(0, 1)], 3);
+    assert_eq!(
+        grid.positions().collect::<Vec<(usize, usize)>>(),
+        vec![(0, 0), (1, 0), (2, 0), (0, 1), (1, 1), (2, 1)]
+    );
+    assert_eq!(
+        grid.rows().collect::<Vec<&[usize]>>(),
+        vec![&[0, 1, 2][..], &[3, 4, 5][..]]
+    );
+    assert_eq!(grid.map(|cell| cell * 2).iter().sum::<usize>(), 30);
+}
+
+#[test]
+fn positions_outside_of_the_grid_do_not_wrap() {
+    let mut grid = numbered_grid();
+    assert_eq!(grid.get((3, 0)), None);
+    assert_eq!(grid.get((0, 2)), None);
+    assert_eq!(grid.get_mut((3, 0)), None);
+    assert_eq!(grid.checked_position(-1, 0), None);
+    assert_eq!(grid.checked_position(2, 1), Some((2, 1)));
+}
+
+#[test]
+#[should_panic(expected = "Position (3, 0) is outside of the 3x2 grid")]
+fn indexing_outside_of_the_grid_panics() {
+    let _ = numbered_grid()[(3, 0)];
+}
+
+#[test]
+fn neighbors_are_clipped_at_the_borders() {
+    let grid = Grid::from_fn(3, 3, |_| ());
+    assert_eq!(
+        grid.neighbors_4((0, 0)).collect::<Vec<(usize, usize)>>(),
+        vec![(1, 0), (0, 1)]
+    );
+    assert_eq!(grid.neighbors_4((1, 1)).count(), 4);
+    assert_eq!(grid.neighbors_8((0, 0)).count(), 3);
+    assert_eq!(grid.neighbors_8((1, 0)).count(), 5);
+    assert_eq!(grid.neighbors_8((1, 1)).count(), 8);
+}
+
+#[test]
+fn ragged_rows_are_not_a_grid() {
+    assert_eq!(Grid::from_rows(vec![vec![1, 2], vec![3]]), None);
+    assert!(Grid::from_rows(vec![vec![1, 2], vec![3, 4]]).is_some());
+}
+
+#[test]
+fn malformed_digit_grids_are_rejected() {
+    for (input, expected) in [
+        ("12\n3x", (2, 2, "Invalid digit")),
+        ("12\n345", (2, 1, "Expected a row with 2 cells instead of")),
+        ("12\n\n34", (2, 1, "Empty grid row")),
+        ("", (1, 1, "Missing grid rows")),
+    ] {
+        let violations = Grid::parse_digits(9, input).unwrap_err().violations;
+        let violation = &violations[0];
+        assert_eq!(
+            (violation.line, violation.column, violation.message.as_str()),
+            expected,
+            "{:?}",
+            input
+        );
+    }
+    assert_eq!(
+        Grid::parse_digits(9, "12\n34").unwrap(),
+        Grid::from_fn(2, 2, |(x, y)| (y * 2 + x + 1) as u32)
+    );
+}